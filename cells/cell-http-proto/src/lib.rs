@@ -31,8 +31,12 @@ pub enum ServeContent {
         mime: String,
         generation: u64,
     },
-    /// Redirect to another URL (302 temporary redirect)
-    Redirect { location: String, generation: u64 },
+    /// Redirect to another URL: 301 when `permanent`, otherwise 302
+    Redirect {
+        location: String,
+        permanent: bool,
+        generation: u64,
+    },
     /// Not found - rendered 404 HTML page
     NotFound { html: String, generation: u64 },
 }
@@ -185,9 +185,14 @@ pub fn build_router(ctx: Arc<dyn RouterContext>) -> axum::Router {
                 .unwrap(),
             ServeContent::Redirect {
                 location,
+                permanent,
                 generation,
             } => Response::builder()
-                .status(StatusCode::FOUND) // 302 Temporary Redirect
+                .status(if permanent {
+                    StatusCode::MOVED_PERMANENTLY
+                } else {
+                    StatusCode::FOUND
+                })
                 .header(header::LOCATION, location)
                 .header(header::CACHE_CONTROL, CACHE_NO_CACHE)
                 .header(header::CONNECTION, "close")
@@ -27,6 +27,19 @@ mod tests {
             weight: 0,
             description: None,
             template: None,
+            date: Some("2024-05-01".to_string()),
+            updated: None,
+            draft: true,
+            publish_at: None,
+            slug: None,
+            aliases: vec!["/old/".to_string()],
+            paginate_by: Some(10),
+            feeds: vec!["atom".to_string()],
+            sitemap: Some(false),
+            sitemap_priority: Some(0.5),
+            related: Some(false),
+            taxonomies: Value::default(),
+            series: Value::default(),
             extra: Value::from(extra),
         };
 
@@ -38,6 +51,14 @@ mod tests {
 
         // Verify extra fields survived
         assert_eq!(fm2.title, "Test");
+        assert_eq!(fm2.date.as_deref(), Some("2024-05-01"));
+        assert!(fm2.draft);
+        assert_eq!(fm2.aliases, ["/old/"]);
+        assert_eq!(fm2.paginate_by, Some(10));
+        assert_eq!(fm2.feeds, ["atom"]);
+        assert_eq!(fm2.sitemap, Some(false));
+        assert_eq!(fm2.sitemap_priority, Some(0.5));
+        assert_eq!(fm2.related, Some(false));
         match fm2.extra.destructure_ref() {
             DestructuredRef::Object(obj) => {
                 let sidebar = obj.get("sidebar").expect("sidebar should exist");
@@ -156,6 +177,32 @@ pub struct Frontmatter {
     pub weight: i32,
     pub description: Option<String>,
     pub template: Option<String>,
+    /// Publication date as written (`YYYY-MM-DD` or RFC 3339)
+    pub date: Option<String>,
+    /// Last-updated date as written
+    pub updated: Option<String>,
+    /// Whether the document is a draft
+    pub draft: bool,
+    /// Embargo date as written
+    pub publish_at: Option<String>,
+    /// Override for the last URL segment
+    pub slug: Option<String>,
+    /// Former URLs redirected to this document
+    pub aliases: Vec<String>,
+    /// Section listing page size
+    pub paginate_by: Option<u32>,
+    /// Feed formats the section publishes
+    pub feeds: Vec<String>,
+    /// `false` leaves the document out of the sitemap
+    pub sitemap: Option<bool>,
+    /// Sitemap priority (0.0 to 1.0)
+    pub sitemap_priority: Option<f64>,
+    /// `false` keeps the document out of related-page suggestions
+    pub related: Option<bool>,
+    /// Taxonomy terms keyed by taxonomy name
+    pub taxonomies: Value,
+    /// Series table (`name`, `part`)
+    pub series: Value,
     /// Extra fields from frontmatter
     pub extra: Value,
 }
@@ -191,7 +238,7 @@ pub enum MarkdownResult {
 pub enum FrontmatterResult {
     /// Successfully parsed frontmatter
     Success {
-        frontmatter: Frontmatter,
+        frontmatter: Box<Frontmatter>,
         /// The remaining content after frontmatter
         body: String,
     },
@@ -215,7 +262,7 @@ pub enum HighlightResult {
 pub enum ParseResult {
     /// Successfully parsed
     Success {
-        frontmatter: Frontmatter,
+        frontmatter: Box<Frontmatter>,
         html: String,
         headings: Vec<Heading>,
         /// Requirement definitions for specification traceability
@@ -165,7 +165,7 @@ impl MarkdownProcessor for MarkdownProcessorImpl {
     async fn parse_frontmatter(&self, content: String) -> FrontmatterResult {
         match marq::parse_frontmatter(&content) {
             Ok((fm, body)) => FrontmatterResult::Success {
-                frontmatter: convert_frontmatter(fm),
+                frontmatter: Box::new(convert_frontmatter(fm)),
                 body: body.to_string(),
             },
             Err(e) => FrontmatterResult::Error {
@@ -280,7 +280,7 @@ impl MarkdownProcessor for MarkdownProcessorImpl {
                     "markdown cell parse_and_render finished"
                 );
                 ParseResult::Success {
-                    frontmatter: convert_frontmatter(fm),
+                    frontmatter: Box::new(convert_frontmatter(fm)),
                     html,
                     headings,
                     reqs,
@@ -325,6 +325,19 @@ fn convert_frontmatter(fm: marq::Frontmatter) -> Frontmatter {
         weight: fm.weight,
         description: fm.description,
         template: fm.template,
+        date: fm.date,
+        updated: fm.updated,
+        draft: fm.draft,
+        publish_at: fm.publish_at,
+        slug: fm.slug,
+        aliases: fm.aliases,
+        paginate_by: fm.paginate_by,
+        feeds: fm.feeds,
+        sitemap: fm.sitemap,
+        sitemap_priority: fm.sitemap_priority,
+        related: fm.related,
+        taxonomies: fm.taxonomies,
+        series: fm.series,
         extra: fm.extra, // Direct pass-through, no JSON conversion!
     }
 }
@@ -1,8 +1,9 @@
 //! Typed interface for dodeca's full-text search indexing processor.
 //!
 //! The search indexer receives the rendered HTML of every page and returns the
-//! complete set of search-index files to write under `/search/`. The on-disk
-//! format of those file contents lives in `dodeca-search-format`.
+//! complete set of search-index files to write under `/search/` (a translation
+//! language gets its own index under `<prefix>/search/`). The on-disk format of
+//! those file contents lives in `dodeca-search-format`.
 
 use facet::Facet;
 
@@ -40,9 +41,11 @@ pub enum SearchIndexResult {
 
 /// Search indexer interface.
 ///
-/// Dodeca calls this once per build with every rendered HTML page.
+/// Dodeca calls this once per build and language with every rendered HTML
+/// page in that language.
 #[allow(async_fn_in_trait)]
 pub trait SearchIndexer {
-    /// Build a full-text search index from the given pages.
-    async fn build_index(&self, pages: Vec<SearchPage>) -> SearchIndexResult;
+    /// Build a full-text search index from the given pages, with every file
+    /// under `base` (`/search`, or `/fr/search` for a translation language).
+    async fn build_index(&self, pages: Vec<SearchPage>, base: String) -> SearchIndexResult;
 }
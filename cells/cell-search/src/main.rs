@@ -3,7 +3,7 @@
 //! Receives the rendered HTML of every page, extracts searchable text and the
 //! heading structure with hotmeal, and builds a sharded inverted index in the
 //! `dodeca-search-format` postcard layout. Dodeca writes the returned files
-//! under `/search/` as static site assets.
+//! under `/search/` (or a language's `<prefix>/search/`) as static site assets.
 
 use std::collections::BTreeMap;
 
@@ -25,9 +25,9 @@ const SKIP_TAGS: &[&str] = &[
 pub struct SearchIndexerImpl;
 
 impl SearchIndexer for SearchIndexerImpl {
-    async fn build_index(&self, pages: Vec<SearchPage>) -> SearchIndexResult {
+    async fn build_index(&self, pages: Vec<SearchPage>, base: String) -> SearchIndexResult {
         let page_count = pages.len();
-        match build(pages) {
+        match build(pages, &base) {
             Ok(files) => {
                 tracing::info!(
                     pages = page_count,
@@ -200,8 +200,9 @@ fn extract(page: &SearchPage) -> ExtractedDoc {
 // Index construction
 // ============================================================================
 
-/// Build the full set of `/search/` files from the given pages.
-fn build(pages: Vec<SearchPage>) -> Result<Vec<SearchFile>, String> {
+/// Build the full set of index files under `base` (`/search`) from the given
+/// pages.
+fn build(pages: Vec<SearchPage>, base: &str) -> Result<Vec<SearchFile>, String> {
     let mut docs: Vec<fmt::DocMeta> = Vec::with_capacity(pages.len());
     let mut fragments: Vec<fmt::Fragment> = Vec::with_capacity(pages.len());
     // term -> doc -> ascending positions
@@ -232,7 +233,7 @@ fn build(pages: Vec<SearchPage>) -> Result<Vec<SearchFile>, String> {
             title: ex.title.clone(),
             source: page.source.clone(),
             len,
-            fragment: format!("/search/fragment/{doc_id}"),
+            fragment: format!("{base}/fragment/{doc_id}"),
         });
         fragments.push(fmt::Fragment {
             url: page.url.clone(),
@@ -264,7 +265,7 @@ fn build(pages: Vec<SearchPage>) -> Result<Vec<SearchFile>, String> {
             .push(fmt::TermPostings { term, postings });
     }
 
-    // The fixed `/search/` paths the served index lives at.
+    // The fixed paths under `base` the served index lives at.
     // s[impl serve.index-paths]
     let mut files: Vec<SearchFile> = Vec::new();
     let mut shard_refs: Vec<fmt::ShardRef> = Vec::new();
@@ -275,7 +276,7 @@ fn build(pages: Vec<SearchPage>) -> Result<Vec<SearchFile>, String> {
         } else {
             prefix.as_str()
         };
-        let path = format!("/search/index/{name}");
+        let path = format!("{base}/index/{name}");
         let shard = fmt::Shard { terms };
         files.push(SearchFile {
             path: path.clone(),
@@ -286,7 +287,7 @@ fn build(pages: Vec<SearchPage>) -> Result<Vec<SearchFile>, String> {
 
     for (i, fragment) in fragments.iter().enumerate() {
         files.push(SearchFile {
-            path: format!("/search/fragment/{i}"),
+            path: format!("{base}/fragment/{i}"),
             contents: fmt::encode(fragment).map_err(|e| format!("encode fragment {i}: {e}"))?,
         });
     }
@@ -299,7 +300,7 @@ fn build(pages: Vec<SearchPage>) -> Result<Vec<SearchFile>, String> {
         shards: shard_refs,
     };
     files.push(SearchFile {
-        path: "/search/meta".to_string(),
+        path: format!("{base}/meta"),
         contents: fmt::encode(&meta).map_err(|e| format!("encode meta: {e}"))?,
     });
 
@@ -326,7 +327,7 @@ mod tests {
                 html: "<main><h1>Markdown</h1><p>Markdown rendering pipeline.</p></main>".into(),
             },
         ];
-        let files = build(pages).unwrap();
+        let files = build(pages, "/search").unwrap();
 
         let meta_bytes = &files
             .iter()
@@ -376,7 +377,7 @@ mod tests {
                 html: "<main><h1>Images</h1><p>Image processing is unrelated.</p></main>".into(),
             },
         ];
-        let files = build(pages).unwrap();
+        let files = build(pages, "/search").unwrap();
         let meta: fmt::SearchMeta = fmt::decode(
             &files
                 .iter()
@@ -400,4 +401,30 @@ mod tests {
         assert_eq!(hits.len(), 1);
         assert_eq!(meta.docs[hits[0].doc as usize].url, "/a/");
     }
+
+    #[test]
+    fn index_files_live_under_base() {
+        let pages = vec![SearchPage {
+            url: "/fr/guide/".into(),
+            source: String::new(),
+            html: "<main><h1>Guide</h1><p>Premiers pas.</p></main>".into(),
+        }];
+        let files = build(pages, "/fr/search").unwrap();
+        assert!(files.iter().all(|f| f.path.starts_with("/fr/search/")));
+
+        let meta: fmt::SearchMeta = fmt::decode(
+            &files
+                .iter()
+                .find(|f| f.path == "/fr/search/meta")
+                .unwrap()
+                .contents,
+        )
+        .unwrap();
+        assert_eq!(meta.docs[0].fragment, "/fr/search/fragment/0");
+        assert!(
+            meta.shards
+                .iter()
+                .all(|s| s.file.starts_with("/fr/search/index/"))
+        );
+    }
 }
@@ -4,8 +4,9 @@ use camino::{Utf8Path, Utf8PathBuf};
 use dodeca::config::{LinkCheckMode, ResolvedConfig};
 use dodeca::db::{
     self, CodeCoverageRegistry, CodeRegistry, ConfigRegistry, DataFile, DataRegistry, Database,
-    MarkdownRenderSettings, OutputFile, QueryStats, SassFile, SassRegistry, SourceFile,
-    SourceRegistry, StaticFile, StaticRegistry, TemplateFile, TemplateRegistry,
+    MarkdownRenderSettings, OutputFile, PublishClock, PublishSettings, QueryStats, SassFile,
+    SassRegistry, SourceFile, SourceRegistry, StaticFile, StaticRegistry, TemplateFile,
+    TemplateRegistry,
 };
 use dodeca::queries::{self, build_site};
 use dodeca::tui::{self, LogEvent};
@@ -1736,6 +1737,7 @@ fn load_lsp_db(
         ConfigRegistry::set(&*ctx.db, global)?;
     }
     MarkdownRenderSettings::set(&*ctx.db, false, true)?;
+    PublishSettings::set(&*ctx.db, true)?;
     ctx.load_sources()?;
     ctx.load_templates()?;
     ctx.load_sass()?;
@@ -1786,11 +1788,16 @@ pub async fn build(
     // Load picante cache from disk (for font subsetting, image processing, etc.)
     let picante_cache_path = cache_dir.join("dodeca.bin");
     load_picante_cache(&ctx.db, &picante_cache_path).await;
+    if let Some(global) = dodeca::config::global_config() {
+        ConfigRegistry::set(&*ctx.db, global)?;
+    }
     MarkdownRenderSettings::set(
         &*ctx.db,
         render_options.source_maps,
         render_options.render_notes,
     )?;
+    PublishSettings::set(&*ctx.db, render_options.dev_mode)?;
+    PublishClock::set(&*ctx.db, dodeca::schedule::now())?;
 
     // Phase 1: Load everything into picante
     ctx.load_sources()?;
@@ -2415,7 +2422,7 @@ fn handle_file_changed(
                 SassRegistry::set(db, sass_files).expect("failed to set sass files");
             }
         }
-        PathCategory::Static | PathCategory::Dist => {
+        PathCategory::Static | PathCategory::Dist | PathCategory::BundleAsset => {
             if let Ok(content) = fs::read(path) {
                 // Skip empty files (transient state during git operations)
                 if content.is_empty() {
@@ -2571,7 +2578,7 @@ fn handle_file_removed(
                 SassRegistry::set(db, sass_files).expect("failed to set sass files");
             }
         }
-        PathCategory::Static | PathCategory::Dist => {
+        PathCategory::Static | PathCategory::Dist | PathCategory::BundleAsset => {
             let db = &*server.db;
             let mut static_files = StaticRegistry::files(db).ok().flatten().unwrap_or_default();
             if let Some(pos) = static_files.iter().position(|s| {
@@ -2658,6 +2665,32 @@ fn prune_missing_sources(config: &file_watcher::WatcherConfig, server: &serve::S
     }
 }
 
+/// Drop static files whose file is gone. Content moves are the only events
+/// that can strand one: moving (or deleting) a page bundle's directory, or
+/// its `index.md`, takes its co-located assets with it without an event per
+/// asset.
+fn prune_missing_static_files(config: &file_watcher::WatcherConfig, server: &serve::SiteServer) {
+    let db = &*server.db;
+    let static_files = StaticRegistry::files(db).ok().flatten().unwrap_or_default();
+    let before = static_files.len();
+
+    let retained: Vec<StaticFile> = static_files
+        .into_iter()
+        .filter(|file| {
+            file.path(db)
+                .is_ok_and(|path| config.static_key_on_disk(path.as_str()))
+        })
+        .collect();
+
+    if retained.len() != before {
+        tracing::debug!(
+            removed = before - retained.len(),
+            "prune_missing_static_files: removing static files missing from disk"
+        );
+        StaticRegistry::set(db, retained).expect("failed to set static files");
+    }
+}
+
 /// Counts of files loaded into each registry, for caller-side logging.
 struct RegistryCounts {
     sources: usize,
@@ -2710,7 +2743,8 @@ fn load_all_registries(
     server.set_templates(templates);
 
     // Static files: primary static/, then dist/ (overrides), then each source's
-    // mount-prefixed static, plus the hidden vite manifest.
+    // mount-prefixed static, plus the hidden vite manifest and the page
+    // bundles' co-located assets.
     let static_count = {
         let db = &*server.db;
         let mut static_files_map: std::collections::BTreeMap<String, StaticFile> =
@@ -2746,6 +2780,9 @@ fn load_all_registries(
         for (path, file) in dodeca::build_context::load_source_static_files(db, sources)? {
             static_files_map.insert(path.as_str().to_string(), file);
         }
+        for (path, file) in dodeca::build_context::load_bundle_assets(db, sources)? {
+            static_files_map.insert(path.as_str().to_string(), file);
+        }
         let count = static_files_map.len();
         server.set_static_files(static_files_map.into_values().collect());
         count
@@ -2983,6 +3020,27 @@ fn apply_file_events_blocking(
 ) {
     let expanded = expand_file_events(batch, config, on_event);
 
+    // A new `index.md` turns its directory into a bundle: pick up the
+    // assets already sitting next to it.
+    let new_bundles: Vec<file_watcher::FileEvent> = expanded
+        .iter()
+        .filter_map(|event| match event {
+            file_watcher::FileEvent::Changed(path) if path.file_name() == Some("index.md") => {
+                path.parent()
+            }
+            _ => None,
+        })
+        .flat_map(|dir| file_watcher::scan_directory_recursive(dir.as_std_path(), config))
+        .filter(|event| match event {
+            file_watcher::FileEvent::Changed(path) => {
+                config.categorize(path) == file_watcher::PathCategory::BundleAsset
+            }
+            _ => false,
+        })
+        .collect();
+    let mut expanded = expanded;
+    expanded.extend(new_bundles);
+
     let should_prune_sources = expanded.iter().any(|event| match event {
         file_watcher::FileEvent::Changed(path) | file_watcher::FileEvent::Removed(path) => {
             config.categorize(path) == file_watcher::PathCategory::Content
@@ -3006,6 +3064,7 @@ fn apply_file_events_blocking(
 
     if should_prune_sources {
         prune_missing_sources(config, server);
+        prune_missing_static_files(config, server);
     }
 }
 
@@ -3725,6 +3784,8 @@ async fn serve_plain(
     // arrived during the initial disk load have been reconciled into the registries.
     server.end_revision(startup_revision);
     tracing::info!("startup revision ready (serve_plain)");
+    // Publish `publish_at`-embargoed pages as their time comes.
+    dodeca::schedule::spawn_clock(server.clone());
 
     // Print server URLs (LISTENING_PORT already printed by the HTTP server)
     // Use "0.0.0.0" to trigger multi-interface display when --public is used
@@ -3954,6 +4015,7 @@ async fn serve_with_tui(
     // Mark startup revision ready before accepting requests.
     server.end_revision(startup_revision);
     tracing::info!("startup revision ready (serve_with_tui)");
+    dodeca::schedule::spawn_clock(server.clone());
 
     let _ = event_tx.send(LogEvent::server(
         "Server ready - content served from memory",
@@ -1130,6 +1130,7 @@ impl Backend {
                         )?)
                     }
                     AuthoringDiagnosticKind::Route
+                    | AuthoringDiagnosticKind::AliasedRoute
                     | AuthoringDiagnosticKind::Anchor
                     | AuthoringDiagnosticKind::Source
                     | AuthoringDiagnosticKind::StaticAsset
@@ -1176,6 +1177,9 @@ impl Backend {
                     &diagnostic,
                     &lsp_diagnostics,
                 )),
+                AuthoringDiagnosticKind::AliasedRoute => actions.extend(
+                    aliased_route_code_actions(&uri, &content, &diagnostic, &lsp_diagnostics),
+                ),
                 AuthoringDiagnosticKind::Anchor => actions.extend(missing_anchor_code_actions(
                     &dirs.content_dir,
                     project,
@@ -2414,12 +2418,20 @@ pub fn frontmatter_diagnostics_for_source(
         }
 
         if spec.kind == FrontmatterFieldKind::Table {
+            let message = if spec.name == "extra" {
+                "frontmatter custom fields belong under an [extra] table".to_string()
+            } else {
+                format!(
+                    "frontmatter field '{}' belongs in a [{}] table",
+                    spec.name, spec.name
+                )
+            };
             diagnostics.push(frontmatter_diagnostic(
                 source_file,
                 route,
                 content,
                 &entry.key,
-                "frontmatter custom fields belong under an [extra] table".to_string(),
+                message,
                 entry.key_start,
                 entry.key_end,
             ));
@@ -2516,7 +2528,15 @@ pub fn diagnostic_for_reference(
         } else {
             let target_route = route_for_link_target(project, page, target_without_fragment);
 
-            if !project.route_exists(&target_route) {
+            if !project.route_exists(&target_route)
+                && let Some(new_route) = project.redirect_target(&target_route)
+            {
+                (
+                    AuthoringDiagnosticKind::AliasedRoute,
+                    Some(new_route.to_string()),
+                    format!("route '{target_route}' redirects to '{new_route}'"),
+                )
+            } else if !project.route_exists(&target_route) {
                 (
                     AuthoringDiagnosticKind::Route,
                     Some(target_route.clone()),
@@ -2649,6 +2669,43 @@ pub fn create_frontmatter_code_action(
     }))
 }
 
+/// Point a link at the route its alias redirects to, keeping any fragment.
+pub fn aliased_route_code_actions(
+    uri: &Url,
+    source_content: &str,
+    diagnostic: &AuthoringDiagnostic,
+    lsp_diagnostics: &[Diagnostic],
+) -> Vec<CodeActionOrCommand> {
+    let Some(new_route) = diagnostic.resolved_route.as_deref() else {
+        return Vec::new();
+    };
+    let Some(context) = target_context_for_diagnostic(source_content, diagnostic) else {
+        return Vec::new();
+    };
+    let (_, fragment) = split_fragment(&diagnostic.target);
+    let new_target = match fragment {
+        Some(fragment) => format!("{new_route}#{fragment}"),
+        None => new_route.to_string(),
+    };
+    let edit = WorkspaceEdit::new(HashMap::from([(
+        uri.clone(),
+        vec![TextEdit::new(context.range, new_target)],
+    )]));
+
+    vec![CodeActionOrCommand::CodeAction(CodeAction {
+        title: format!("Link to '{new_route}'"),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(lsp_diagnostics_for_range(
+            lsp_diagnostics,
+            diagnostic.range(),
+        )),
+        edit: Some(edit),
+        command: None,
+        is_preferred: Some(true),
+        ..CodeAction::default()
+    })]
+}
+
 pub fn missing_anchor_code_actions(
     content_dir: &Utf8Path,
     project: &AuthoringProject,
@@ -4167,6 +4224,16 @@ pub fn page_route_rename_plan(
     target_page: &AuthoringPage,
     new_name: &str,
 ) -> Result<Option<PageRouteRenamePlan>> {
+    // A route laid out by frontmatter (a permalink pattern or slug) or moved
+    // under a language prefix doesn't follow the file, so moving the file
+    // can't rename it. A page bundle's route is its directory, which carries
+    // the bundle's assets: moving `index.md` alone would strand them.
+    let source_path = SourcePath::new(target_page.source_file.clone());
+    if source_path.is_bundle_index()
+        || normalize_route(source_path.to_route().as_str()) != normalize_route(&target_page.route)
+    {
+        return Ok(None);
+    }
     let Some(target) = page_route_rename_target(target_page, new_name) else {
         return Ok(None);
     };
@@ -4971,8 +5038,17 @@ pub struct TemplateCompletionContext {
     pub range: Range,
 }
 
-pub const TEMPLATE_ROOT_VALUES: &[&str] =
-    &["config", "page", "section", "current_path", "root", "data"];
+pub const TEMPLATE_ROOT_VALUES: &[&str] = &[
+    "config",
+    "page",
+    "section",
+    "current_path",
+    "root",
+    "data",
+    "taxonomy",
+    "term",
+    "paginator",
+];
 pub const TEMPLATE_CONFIG_FIELDS: &[&str] = &["title", "description", "base_url"];
 pub const TEMPLATE_PAGE_FIELDS: &[&str] = &[
     "title",
@@ -4984,6 +5060,10 @@ pub const TEMPLATE_PAGE_FIELDS: &[&str] = &[
     "ancestors",
     "last_updated",
     "description",
+    "taxonomies",
+    "series",
+    "lang",
+    "translations",
     "extra",
 ];
 pub const TEMPLATE_SECTION_FIELDS: &[&str] = &[
@@ -4997,8 +5077,24 @@ pub const TEMPLATE_SECTION_FIELDS: &[&str] = &[
     "pages",
     "subsections",
     "toc",
+    "lang",
+    "translations",
     "extra",
 ];
+pub const TEMPLATE_TAXONOMY_FIELDS: &[&str] = &["name", "title", "path", "permalink", "terms"];
+pub const TEMPLATE_TERM_FIELDS: &[&str] = &["name", "slug", "path", "permalink", "pages"];
+pub const TEMPLATE_PAGINATOR_FIELDS: &[&str] = &[
+    "pages",
+    "current_index",
+    "number_pagers",
+    "paginate_by",
+    "total_pages",
+    "first",
+    "last",
+    "previous",
+    "next",
+    "permalink",
+];
 
 pub fn template_root_info(name: &str) -> TemplateItemInfo {
     match name {
@@ -5026,6 +5122,18 @@ pub fn template_root_info(name: &str) -> TemplateItemInfo {
             detail: "Data registry",
             documentation: "Lazy access to parsed files from the Dodeca `data/` directory.",
         },
+        "taxonomy" => TemplateItemInfo {
+            detail: "Current taxonomy",
+            documentation: "The taxonomy being rendered on `taxonomy.html` and `taxonomy_term.html`, such as `tags`.",
+        },
+        "term" => TemplateItemInfo {
+            detail: "Current taxonomy term",
+            documentation: "The term rendered by `taxonomy_term.html`, with the pages tagged with it, newest first.",
+        },
+        "paginator" => TemplateItemInfo {
+            detail: "Section paginator",
+            documentation: "Set on sections with `paginate_by`: this pager's slice of `section.pages` and links to the other pagers.",
+        },
         _ => TemplateItemInfo {
             detail: "Dodeca template context",
             documentation: "A value supplied by Dodeca to Gingembre templates.",
@@ -5084,6 +5192,38 @@ pub fn template_field_info(path: &[String], name: &str) -> TemplateItemInfo {
             detail: "Child sections",
             documentation: "Immediate child sections below this section, sorted by weight.",
         },
+        (_, "taxonomies") => TemplateItemInfo {
+            detail: "Page taxonomy terms",
+            documentation: "Terms from the `[taxonomies]` frontmatter table, keyed by taxonomy name.",
+        },
+        ("page", "series") => TemplateItemInfo {
+            detail: "Page series",
+            documentation: "The `[series]` this page is part of: `name`, `part`, `index`, `total`, every part in `pages`, and `prev`/`next` (`null` at the ends). `null` outside a series.",
+        },
+        (_, "lang") => TemplateItemInfo {
+            detail: "Content language",
+            documentation: "Language code from the file name (`intro.fr.md` is `fr`); `null` unless `site.languages` is configured.",
+        },
+        (_, "translations") => TemplateItemInfo {
+            detail: "Language versions",
+            documentation: "Every language version of this content, itself included, with `lang`, `name`, `title`, `path` and `permalink`. Empty for untranslated content.",
+        },
+        ("taxonomy", "terms") => TemplateItemInfo {
+            detail: "Taxonomy terms",
+            documentation: "Every term of this taxonomy with its `name`, `slug`, `path`, `permalink` and `page_count`.",
+        },
+        ("paginator", "pages") => TemplateItemInfo {
+            detail: "Pager pages",
+            documentation: "The slice of `section.pages` shown on this pager.",
+        },
+        ("paginator", "previous" | "next" | "first" | "last") => TemplateItemInfo {
+            detail: "Pager link",
+            documentation: "Site-relative path of another pager; `previous`/`next` are `null` at the ends.",
+        },
+        ("term", "pages") => TemplateItemInfo {
+            detail: "Term pages",
+            documentation: "Pages tagged with this term, newest first.",
+        },
         _ => TemplateItemInfo {
             detail: "Dodeca template field",
             documentation: "Field supplied by Dodeca in the Gingembre template context.",
@@ -5324,6 +5464,9 @@ pub fn template_field_completion_items(
         [root] if root == "config" => TEMPLATE_CONFIG_FIELDS,
         [root] if root == "page" => TEMPLATE_PAGE_FIELDS,
         [root] if root == "section" || root == "root" => TEMPLATE_SECTION_FIELDS,
+        [root] if root == "taxonomy" => TEMPLATE_TAXONOMY_FIELDS,
+        [root] if root == "term" => TEMPLATE_TERM_FIELDS,
+        [root] if root == "paginator" => TEMPLATE_PAGINATOR_FIELDS,
         [root] if root == "data" => {
             return project
                 .data_keys
@@ -6727,8 +6870,10 @@ pub fn builtin_template_definition_range(
 }
 
 pub fn frontmatter_completion_label(spec: FrontmatterFieldSpec) -> &'static str {
-    match spec.kind {
-        FrontmatterFieldKind::Table => "[extra]",
+    match (spec.name, spec.kind) {
+        ("taxonomies", FrontmatterFieldKind::Table) => "[taxonomies]",
+        ("series", FrontmatterFieldKind::Table) => "[series]",
+        (_, FrontmatterFieldKind::Table) => "[extra]",
         _ => spec.name,
     }
 }
@@ -6753,7 +6898,18 @@ pub fn frontmatter_completion_text(source_file: &str, spec: FrontmatterFieldSpec
         ("asset", FrontmatterFieldKind::String) => "asset = \"\"".to_string(),
         ("data", FrontmatterFieldKind::String) => "data = \"\"".to_string(),
         ("weight", FrontmatterFieldKind::Integer) => "weight = 0".to_string(),
+        ("paginate_by", FrontmatterFieldKind::Integer) => "paginate_by = 10".to_string(),
+        ("feeds", FrontmatterFieldKind::List) => "feeds = [\"atom\"]".to_string(),
+        ("sitemap_priority", FrontmatterFieldKind::Float) => "sitemap_priority = 0.5".to_string(),
+        (name, FrontmatterFieldKind::Date) => {
+            format!("{name} = {}", chrono::Local::now().format("%Y-%m-%d"))
+        }
+        ("sitemap", FrontmatterFieldKind::Boolean) => "sitemap = false".to_string(),
+        (name, FrontmatterFieldKind::Boolean) => format!("{name} = true"),
+        ("slug", FrontmatterFieldKind::String) => "slug = \"\"".to_string(),
         ("extra", FrontmatterFieldKind::Table) => "[extra]\n".to_string(),
+        ("taxonomies", FrontmatterFieldKind::Table) => "[taxonomies]\n".to_string(),
+        ("series", FrontmatterFieldKind::Table) => "[series]\nname = \"\"\npart = 1\n".to_string(),
         _ => spec.name.to_string(),
     }
 }
@@ -7043,6 +7199,7 @@ pub fn route_graph_edge_span_to_json(edge: &RouteGraphEdge) -> serde_json::Value
 pub fn diagnostic_kind_name(kind: AuthoringDiagnosticKind) -> &'static str {
     match kind {
         AuthoringDiagnosticKind::Route => "missingRoute",
+        AuthoringDiagnosticKind::AliasedRoute => "aliasedRoute",
         AuthoringDiagnosticKind::Anchor => "missingAnchor",
         AuthoringDiagnosticKind::Source => "missingSource",
         AuthoringDiagnosticKind::StaticAsset => "missingStaticAsset",
@@ -133,6 +133,41 @@ pub struct SiteConfig {
     /// gated; absent → open (local `ddc serve`).
     #[facet(default)]
     pub auth: Option<AuthConfig>,
+
+    /// Taxonomies keyed by name (`tags`, `categories`, …). Each one gets an
+    /// index page at `/<name>/` and a page per term at `/<name>/<term>/`,
+    /// aggregated across every source of the assembled site.
+    #[facet(default)]
+    pub taxonomies: Option<HashMap<String, TaxonomyDef>>,
+
+    /// What section feeds carry (`content`, `limit`). Sections opt in with
+    /// `feeds = ["atom", "rss", "json"]` in their frontmatter.
+    #[facet(default)]
+    pub feeds: Option<FeedsConfig>,
+
+    /// Related-page suggestions (`page.related`), ranked by embedding
+    /// similarity at build time. Absent → `page.related` is empty.
+    #[facet(default)]
+    pub related: Option<RelatedConfig>,
+
+    /// Permanent redirects keyed by old route (`/old/path/ /new/path/`).
+    /// Targets are site routes or absolute URLs; page `aliases` add to these.
+    #[facet(default)]
+    pub redirects: Option<HashMap<String, String>>,
+
+    /// Route patterns keyed by section route (`/blog`) or page type (`post`),
+    /// such as `/blog/{year}/{month}/{slug}/`, filled in from frontmatter.
+    #[facet(default)]
+    pub permalinks: Option<HashMap<String, String>>,
+
+    /// Language code of untranslated content (`en`). Defaults to `en`.
+    #[facet(default)]
+    pub default_language: Option<String>,
+
+    /// Translation languages keyed by code. `page.fr.md` is the `fr`
+    /// translation of `page.md`, served under that language's route prefix.
+    #[facet(default)]
+    pub languages: Option<HashMap<String, LanguageDef>>,
 }
 
 /// A frontmatter schema type.
@@ -417,6 +452,118 @@ pub struct SyntaxHighlightConfig {
     pub dark_theme: Option<String>,
 }
 
+/// A taxonomy declaration.
+///
+/// Pages opt into terms from frontmatter:
+/// ```toml
+/// [taxonomies]
+/// tags = ["rust", "async"]
+/// ```
+///
+/// Example in `.config/dodeca.styx`:
+/// ```styx
+/// site {
+///   taxonomies {
+///     tags { title Tags }
+///     categories {}
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Facet)]
+#[facet(rename_all = "snake_case")]
+pub struct TaxonomyDef {
+    /// Display title for the index page. Defaults to the title-cased name.
+    #[facet(default)]
+    pub title: Option<String>,
+}
+
+/// A content language.
+///
+/// Example in `.config/dodeca.styx`:
+/// ```styx
+/// site {
+///   default_language en
+///   languages {
+///     en { name English }
+///     fr {
+///       name Français
+///       prefix /fr
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Facet)]
+#[facet(rename_all = "snake_case")]
+pub struct LanguageDef {
+    /// Display name, for language switchers. Defaults to the code.
+    #[facet(default)]
+    pub name: Option<String>,
+
+    /// Route prefix for this language. Defaults to `/<code>`; the default
+    /// language is always served unprefixed.
+    #[facet(default)]
+    pub prefix: Option<String>,
+}
+
+/// How much of each page a feed entry carries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Facet)]
+#[facet(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum FeedContent {
+    /// The full rendered page body.
+    #[default]
+    Full,
+    /// The page description, or the first paragraph of its body.
+    Summary,
+}
+
+/// Feed generation settings.
+///
+/// Example in `.config/dodeca.styx`:
+/// ```styx
+/// site {
+///   feeds {
+///     content summary
+///     limit 20
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Facet)]
+#[facet(rename_all = "snake_case")]
+pub struct FeedsConfig {
+    /// Full bodies or summaries. Defaults to `full`.
+    #[facet(default)]
+    pub content: Option<FeedContent>,
+
+    /// Newest entries to keep per feed. Defaults to all of them.
+    #[facet(default)]
+    pub limit: Option<usize>,
+}
+
+/// Related-page settings.
+///
+/// Example in `.config/dodeca.styx`:
+/// ```styx
+/// site {
+///   related {
+///     k 3
+///     within section
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Facet)]
+#[facet(rename_all = "snake_case")]
+pub struct RelatedConfig {
+    /// Suggestions per page. Defaults to 5.
+    #[facet(default)]
+    pub k: Option<usize>,
+
+    /// Only suggest pages from the same `section`, or sharing a term of the
+    /// named taxonomy (`tags`). Defaults to the whole site.
+    #[facet(default)]
+    pub within: Option<String>,
+}
+
 /// What to check.
 ///
 /// `Full` (default) walks every internal link and probes every external one;
@@ -599,6 +746,13 @@ impl LegacyConfig {
             code_execution,
             syntax_highlight,
             auth,
+            taxonomies: None,
+            feeds: None,
+            related: None,
+            redirects: None,
+            permalinks: None,
+            default_language: None,
+            languages: None,
         };
 
         match sources {
@@ -717,4 +871,61 @@ mod legacy_tests {
         assert!(!legacy, "modern config must not be treated as v1");
         assert_eq!(cfg.source.unwrap().content.as_deref(), Some("content"));
     }
+
+    #[test]
+    fn site_taxonomies_parse() {
+        let text = "source {\n  content content\n}\n\
+            site {\n  output public\n  taxonomies {\n    tags { title Tags }\n    categories {}\n  }\n}\n";
+        let (cfg, _) = parse_config(text).unwrap();
+        let taxonomies = cfg.site.unwrap().taxonomies.expect("taxonomies");
+        assert_eq!(taxonomies.len(), 2);
+        assert_eq!(taxonomies["tags"].title.as_deref(), Some("Tags"));
+        assert_eq!(taxonomies["categories"].title, None);
+    }
+
+    #[test]
+    fn site_feeds_parse() {
+        let text = "source {\n  content content\n}\n\
+            site {\n  output public\n  feeds {\n    content summary\n    limit 10\n  }\n}\n";
+        let (cfg, _) = parse_config(text).unwrap();
+        let feeds = cfg.site.unwrap().feeds.expect("feeds");
+        assert_eq!(feeds.content, Some(FeedContent::Summary));
+        assert_eq!(feeds.limit, Some(10));
+    }
+
+    #[test]
+    fn site_related_parse() {
+        let text = "source {\n  content content\n}\n\
+            site {\n  output public\n  related {\n    k 3\n    within tags\n  }\n}\n";
+        let (cfg, _) = parse_config(text).unwrap();
+        let related = cfg.site.unwrap().related.expect("related");
+        assert_eq!(related.k, Some(3));
+        assert_eq!(related.within.as_deref(), Some("tags"));
+    }
+
+    #[test]
+    fn site_redirects_parse() {
+        let text = "source {\n  content content\n}\n\
+            site {\n  output public\n  redirects {\n    /old/ /new/\n    /docs \"https://docs.example.com/\"\n  }\n}\n";
+        let (cfg, _) = parse_config(text).unwrap();
+        let redirects = cfg.site.unwrap().redirects.expect("redirects");
+        assert_eq!(redirects.get("/old/").map(String::as_str), Some("/new/"));
+        assert_eq!(
+            redirects.get("/docs").map(String::as_str),
+            Some("https://docs.example.com/")
+        );
+    }
+
+    #[test]
+    fn site_languages_parse() {
+        let text = "source {\n  content content\n}\n\
+            site {\n  output public\n  default_language en\n  languages {\n    en { name English }\n    fr {\n      name Français\n      prefix /fr\n    }\n  }\n}\n";
+        let (cfg, _) = parse_config(text).unwrap();
+        let site = cfg.site.unwrap();
+        assert_eq!(site.default_language.as_deref(), Some("en"));
+        let languages = site.languages.expect("languages");
+        assert_eq!(languages["en"].name.as_deref(), Some("English"));
+        assert_eq!(languages["en"].prefix, None);
+        assert_eq!(languages["fr"].prefix.as_deref(), Some("/fr"));
+    }
 }
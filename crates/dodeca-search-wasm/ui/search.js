@@ -23,9 +23,17 @@ const DEBOUNCE_MS = 120;
 // (unnamed) source, in which case search stays a flat global list.
 const SOURCES = Array.isArray(window.__dodecaSources) ? window.__dodecaSources : [];
 
+// Route prefixes of translation languages (["/fr"]), injected by the page head.
+// Each language has its own index under `<prefix>/search/`; the default
+// language's lives at `/search/`.
+const LANGUAGE_PREFIXES = Array.isArray(window.__dodecaLanguages) ? window.__dodecaLanguages : [];
+const LANGUAGE_PREFIX =
+  LANGUAGE_PREFIXES.find((p) => location.pathname === p || location.pathname.startsWith(p + "/")) ?? "";
+
 function currentSourceName() {
   if (SOURCES.length === 0) return null;
-  const path = location.pathname;
+  // Translations sit under their language prefix but keep their source's mount.
+  const path = location.pathname.slice(LANGUAGE_PREFIX.length) || "/";
   let best = null;
   let bestLen = -1;
   for (const s of SOURCES) {
@@ -66,7 +74,7 @@ async function boot() {
 
   try {
     await initWasm();
-    await load_index("/search/meta");
+    await load_index(`${LANGUAGE_PREFIX}/search/meta`);
   } catch (e) {
     console.error("[dodeca-search] index unavailable:", e);
     ui.input.placeholder = "Search unavailable";
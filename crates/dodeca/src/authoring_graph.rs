@@ -71,6 +71,8 @@ pub struct RouteGraphEdge {
 pub enum RouteGraphEdgeKind {
     Markdown,
     RenderedHtml,
+    /// Consecutive parts of a series; `target` is the series name
+    Series,
 }
 
 impl RouteGraphEdgeKind {
@@ -78,6 +80,7 @@ impl RouteGraphEdgeKind {
         match self {
             RouteGraphEdgeKind::Markdown => "markdown",
             RouteGraphEdgeKind::RenderedHtml => "renderedHtml",
+            RouteGraphEdgeKind::Series => "series",
         }
     }
 }
@@ -179,6 +182,36 @@ pub fn route_graph_for_project(project: &AuthoringProject) -> Vec<RouteGraphNode
         }
     }
 
+    // Each part of a series links to the parts before and after it.
+    for (name, routes) in &project.series {
+        for pair in routes.windows(2) {
+            for (source_route, target_route) in [(&pair[0], &pair[1]), (&pair[1], &pair[0])] {
+                let Some(source_page) = project.page_for_route(source_route) else {
+                    continue;
+                };
+                let edge = RouteGraphEdge {
+                    kind: RouteGraphEdgeKind::Series,
+                    source_route: source_route.clone(),
+                    source_file: source_page.source_file.clone(),
+                    target_route: target_route.clone(),
+                    target: name.clone(),
+                    line: None,
+                    column: None,
+                    line_end: None,
+                    column_end: None,
+                };
+                outgoing_by_route
+                    .entry(source_route.clone())
+                    .or_default()
+                    .push(edge.clone());
+                incoming_by_route
+                    .entry(target_route.clone())
+                    .or_default()
+                    .push(edge);
+            }
+        }
+    }
+
     project
         .pages
         .iter()
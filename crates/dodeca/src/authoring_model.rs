@@ -36,6 +36,7 @@ pub struct AuthoringDiagnostic {
 #[repr(u8)]
 pub enum AuthoringDiagnosticKind {
     Route,
+    AliasedRoute,
     Anchor,
     Source,
     StaticAsset,
@@ -66,6 +67,10 @@ pub enum AuthoringInputPath {
 pub struct AuthoringProject {
     pub pages: Vec<AuthoringPage>,
     pub known_routes: HashSet<String>,
+    /// Redirected routes (page aliases, `site.redirects`) and their targets
+    pub redirects: HashMap<String, String>,
+    /// Series names and their member routes, ordered by part
+    pub series: HashMap<String, Vec<String>>,
     pub headings_by_route: HashMap<String, HashSet<String>>,
     pub source_to_route: HashMap<String, String>,
     pub route_to_source: HashMap<String, String>,
@@ -79,8 +84,17 @@ pub struct AuthoringProject {
     pub rendered_hrefs_by_route: HashMap<String, Vec<RenderedHref>>,
 }
 
-const TEMPLATE_CONTEXT_ROOTS: &[&str] =
-    &["config", "page", "section", "current_path", "root", "data"];
+const TEMPLATE_CONTEXT_ROOTS: &[&str] = &[
+    "config",
+    "page",
+    "section",
+    "current_path",
+    "root",
+    "data",
+    "taxonomy",
+    "term",
+    "paginator",
+];
 
 #[derive(Debug, Clone, PartialEq, Eq, facet::Facet)]
 pub struct AuthoringPage {
@@ -519,9 +533,14 @@ pub async fn build_authoring_project_on_db<DB: crate::db::Db>(
         );
     }
 
+    let languages = crate::i18n::site_languages(inputs.db).await?;
     let mut pages = Vec::new();
     for (source_file, route) in &source_to_route {
-        let source_path = SourcePath::new(source_file.clone());
+        // Translations (`_index.fr.md`) are sections like their originals.
+        let source_path = match crate::i18n::split_source_path(source_file, &languages) {
+            Some((_, original)) => SourcePath::new(original),
+            None => SourcePath::new(source_file.clone()),
+        };
         let route_key = Route::new(route.clone());
         if source_path.is_section_index() {
             if let Some(section) = site_tree.sections.get(&route_key) {
@@ -647,7 +666,8 @@ pub async fn build_authoring_project_on_db<DB: crate::db::Db>(
             }
             AuthoringPageKind::Page => {
                 if let Some(page) = site_tree.pages.get(&route) {
-                    render_authoring_html(Renderable::Page(page), &site_tree, templates).await
+                    // Related pages are suggestions, not authored links.
+                    render_authoring_html(Renderable::Page(page, &[]), &site_tree, templates).await
                 } else {
                     None
                 }
@@ -686,9 +706,31 @@ pub async fn build_authoring_project_on_db<DB: crate::db::Db>(
         })
         .collect();
 
+    let redirects = site_tree
+        .redirects
+        .iter()
+        .map(|(from, to)| (from.as_str().to_string(), to.clone()))
+        .collect();
+    let series = site_tree
+        .series
+        .values()
+        .map(|series| {
+            (
+                series.name.clone(),
+                series
+                    .pages
+                    .iter()
+                    .map(|route| route.as_str().to_string())
+                    .collect(),
+            )
+        })
+        .collect();
+
     Ok(AuthoringProject {
         pages,
         known_routes,
+        redirects,
+        series,
         headings_by_route,
         source_to_route,
         route_to_source,
@@ -740,6 +782,13 @@ impl AuthoringProject {
             }
     }
 
+    /// Where a redirected route (page alias or `site.redirects` entry) points.
+    pub fn redirect_target(&self, target_route: &str) -> Option<&str> {
+        let trimmed = target_route.trim_end_matches('/');
+        let key = if trimmed.is_empty() { "/" } else { trimmed };
+        self.redirects.get(key).map(String::as_str)
+    }
+
     pub fn heading_exists(&self, target_route: &str, heading_id: &str) -> Option<bool> {
         self.heading_ids_for_route(target_route)
             .map(|ids| ids.contains(heading_id))
@@ -1758,6 +1758,10 @@ pub struct FrontmatterFieldSpec {
 pub enum FrontmatterFieldKind {
     String,
     Integer,
+    Float,
+    Boolean,
+    Date,
+    List,
     Table,
 }
 
@@ -1802,7 +1806,7 @@ pub fn frontmatter_field_kind(
     name: &'static str,
     shape: &'static facet::Shape,
 ) -> Option<FrontmatterFieldKind> {
-    if name == "extra" {
+    if matches!(name, "extra" | "taxonomies" | "series") {
         return Some(FrontmatterFieldKind::Table);
     }
 
@@ -1815,12 +1819,22 @@ pub fn frontmatter_field_kind(
     if shape.type_identifier == "String" {
         return Some(FrontmatterFieldKind::String);
     }
+    if shape.type_identifier == "FrontmatterDate" {
+        return Some(FrontmatterFieldKind::Date);
+    }
+    if shape.type_identifier == "Vec" {
+        return Some(FrontmatterFieldKind::List);
+    }
 
     match shape.ty {
         Type::Primitive(PrimitiveType::Textual(_)) => Some(FrontmatterFieldKind::String),
+        Type::Primitive(PrimitiveType::Boolean) => Some(FrontmatterFieldKind::Boolean),
         Type::Primitive(PrimitiveType::Numeric(NumericType::Integer { .. })) => {
             Some(FrontmatterFieldKind::Integer)
         }
+        Type::Primitive(PrimitiveType::Numeric(NumericType::Float)) => {
+            Some(FrontmatterFieldKind::Float)
+        }
         _ => None,
     }
 }
@@ -1968,6 +1982,17 @@ pub fn frontmatter_value_matches_kind(value: &str, kind: FrontmatterFieldKind) -
     match kind {
         FrontmatterFieldKind::String => value.starts_with('"') || value.starts_with('\''),
         FrontmatterFieldKind::Integer => frontmatter_value_is_integer(value),
+        FrontmatterFieldKind::Float => {
+            frontmatter_value_is_integer(value) || value.replace('_', "").parse::<f64>().is_ok()
+        }
+        FrontmatterFieldKind::Boolean => matches!(value, "true" | "false"),
+        FrontmatterFieldKind::Date => {
+            crate::dates::parse_frontmatter_date(value).is_some()
+                && (value.starts_with(|c: char| c.is_ascii_digit())
+                    || value.starts_with('"')
+                    || value.starts_with('\''))
+        }
+        FrontmatterFieldKind::List => value.starts_with('['),
         FrontmatterFieldKind::Table => true,
     }
 }
@@ -2094,6 +2119,10 @@ impl FrontmatterFieldKind {
         match self {
             FrontmatterFieldKind::String => "a string",
             FrontmatterFieldKind::Integer => "an integer",
+            FrontmatterFieldKind::Float => "a number",
+            FrontmatterFieldKind::Boolean => "a boolean",
+            FrontmatterFieldKind::Date => "a date (YYYY-MM-DD or RFC 3339)",
+            FrontmatterFieldKind::List => "a list",
             FrontmatterFieldKind::Table => "a table",
         }
     }
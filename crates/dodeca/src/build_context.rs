@@ -189,6 +189,55 @@ pub fn load_source_files(
     Ok(out)
 }
 
+/// The page bundle a content file belongs to: the nearest directory above
+/// `rel` (relative to `content_dir`) that holds an `index.md`, relative too.
+/// The content root itself is never a bundle.
+pub fn bundle_dir(content_dir: &Utf8Path, rel: &Utf8Path) -> Option<Utf8PathBuf> {
+    rel.ancestors()
+        .skip(1)
+        .filter(|dir| !dir.as_str().is_empty())
+        .find(|dir| content_dir.join(dir).join("index.md").is_file())
+        .map(Utf8Path::to_owned)
+}
+
+/// Load the co-located assets of every page bundle (`article/index.md` plus
+/// `article/cover.jpg`) as static files, keyed by their mount-prefixed content
+/// path (`article/cover.jpg`) so they are processed and cache-busted like
+/// anything under `static/`. Non-markdown files outside a bundle are not
+/// published. Shared by `BuildContext` (build) and the serve path.
+pub fn load_bundle_assets(
+    db: &Database,
+    roots: &[ResolvedSource],
+) -> Result<Vec<(StaticPath, StaticFile)>> {
+    let mut out = Vec::new();
+    for root in roots {
+        let assets: Vec<Utf8PathBuf> = WalkBuilder::new(&root.content_dir)
+            .build()
+            .filter_map(|e| e.ok())
+            .filter(|e| {
+                e.file_type()
+                    .map(|ft| ft.is_file() || (ft.is_symlink() && e.path().is_file()))
+                    .unwrap_or(false)
+            })
+            .filter(|e| e.path().extension().is_none_or(|ext| ext != "md"))
+            .filter_map(|e| Utf8PathBuf::from_path_buf(e.into_path()).ok())
+            .collect();
+        for path in assets {
+            let Ok(relative) = path.strip_prefix(&root.content_dir) else {
+                continue;
+            };
+            if bundle_dir(&root.content_dir, relative).is_none() {
+                continue;
+            }
+            let key = mounted_key(&root.mount, relative.as_str());
+            let static_path = StaticPath::new(key);
+            let static_file = StaticFile::new(db, static_path.clone(), fs::read(&path)?)?;
+            out.push((static_path, static_file));
+        }
+    }
+    Ok(out)
+}
+
 /// Absolute paths of every file matched by any source's `impls`
 /// `include`/`test_include` globs (minus `exclude`), found by walking the
 /// project root once. Empty when no source declares `impls`. Shared by
@@ -835,6 +884,11 @@ impl BuildContext {
             self.static_files.insert(path, file);
         }
 
+        // Page bundles' co-located assets, keyed by their content path.
+        for (path, file) in load_bundle_assets(&self.db, &roots)? {
+            self.static_files.insert(path, file);
+        }
+
         Ok(())
     }
 
@@ -198,8 +198,14 @@ pub async fn subset_font(input: SubsetFontInput) -> Result<FontResult, eyre::Err
 }
 
 /// Build the full-text search index from rendered pages.
-pub async fn build_search_index(pages: Vec<SearchPage>) -> Result<Vec<SearchFile>, eyre::Error> {
-    match ddc_cell_search::SearchIndexerImpl.build_index(pages).await {
+pub async fn build_search_index(
+    pages: Vec<SearchPage>,
+    base: String,
+) -> Result<Vec<SearchFile>, eyre::Error> {
+    match ddc_cell_search::SearchIndexerImpl
+        .build_index(pages, base)
+        .await
+    {
         SearchIndexResult::Success { files } => Ok(files),
         SearchIndexResult::Error { message } => {
             Err(eyre::eyre!("search indexing failed: {message}"))
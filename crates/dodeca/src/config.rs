@@ -14,8 +14,8 @@ use std::fs;
 
 // Re-export config types from dodeca-config crate
 pub use dodeca_config::{
-    AuthConfig, CodeExecutionConfig, DodecaConfig, LinkCheckMode, MountDef, PageTypeSchema,
-    SiteConfig, SourceConfig,
+    AuthConfig, CodeExecutionConfig, DodecaConfig, FeedContent, LanguageDef, LinkCheckMode,
+    MountDef, PageTypeSchema, SiteConfig, SourceConfig, TaxonomyDef,
 };
 
 /// Configuration file names
@@ -214,6 +214,32 @@ pub struct ResolvedConfig {
     /// Auth config. `Some` ⇒ gate `/_dodeca/*` on a forwarded identity; `None`
     /// ⇒ open (local dev, no proxy).
     pub auth: Option<AuthConfig>,
+    /// Site-wide taxonomies keyed by name, ordered so the tree is deterministic.
+    pub taxonomies: std::collections::BTreeMap<String, TaxonomyDef>,
+    /// What feed entries carry: full bodies (default) or summaries
+    pub feed_content: FeedContent,
+    /// Newest entries kept per feed; `None` keeps them all
+    pub feed_limit: Option<usize>,
+    /// `site.redirects`: old route to target route or URL
+    pub redirects: std::collections::BTreeMap<String, String>,
+    /// `site.permalinks`: route pattern per section route or page type
+    pub permalinks: std::collections::BTreeMap<String, String>,
+    /// Content languages, default language first. Empty unless
+    /// `site.languages` is set, in which case `page.<lang>.md` is a translation.
+    pub languages: Vec<Language>,
+    /// `site.related`: `page.related` settings; `None` leaves it empty
+    pub related: Option<crate::related::RelatedSettings>,
+}
+
+/// A content language with its defaults filled in.
+#[derive(Debug, Clone, PartialEq, Eq, facet::Facet)]
+pub struct Language {
+    /// Language code (`fr`), as used in file names and `hreflang`
+    pub code: String,
+    /// Display name; the code when none is configured
+    pub name: String,
+    /// Route prefix (`/fr`); empty for the default language
+    pub prefix: String,
 }
 
 impl ResolvedConfig {
@@ -443,6 +469,12 @@ fn resolve_config(root: &Utf8Path, config: DodecaConfig) -> Result<ResolvedConfi
         .and_then(|lc| lc.mode)
         .unwrap_or_default();
     let stable_assets = site.stable_assets.unwrap_or_default();
+    let feed_content = site
+        .feeds
+        .as_ref()
+        .and_then(|f| f.content)
+        .unwrap_or_default();
+    let feed_limit = site.feeds.as_ref().and_then(|f| f.limit);
 
     let light_theme_name = site
         .syntax_highlight
@@ -468,6 +500,10 @@ fn resolve_config(root: &Utf8Path, config: DodecaConfig) -> Result<ResolvedConfi
     // executor) — there is no whole-site build-steps map.
 
     let page_types = merge_page_types(&sources)?;
+    let languages = resolve_languages(site.default_language.as_deref(), site.languages)?;
+    let permalinks = crate::permalinks::resolve(site.permalinks.unwrap_or_default())?;
+    let taxonomies = site.taxonomies.unwrap_or_default().into_iter().collect();
+    let related = crate::related::resolve(site.related, &taxonomies)?;
 
     Ok(ResolvedConfig {
         _root: root.to_owned(),
@@ -484,9 +520,68 @@ fn resolve_config(root: &Utf8Path, config: DodecaConfig) -> Result<ResolvedConfi
         dark_theme_css,
         page_types,
         auth: site.auth,
+        taxonomies,
+        feed_content,
+        feed_limit,
+        redirects: site.redirects.unwrap_or_default().into_iter().collect(),
+        permalinks,
+        languages,
+        related,
     })
 }
 
+/// Resolve `site.languages`: the default language (`en` unless configured)
+/// comes first and is unprefixed, every other language gets `/<code>` unless
+/// it sets its own prefix.
+fn resolve_languages(
+    default_language: Option<&str>,
+    languages: Option<std::collections::HashMap<String, LanguageDef>>,
+) -> Result<Vec<Language>> {
+    let Some(mut defs) = languages else {
+        return Ok(Vec::new());
+    };
+    let default_code = default_language.unwrap_or("en").to_string();
+    let default_def = defs.remove(&default_code).unwrap_or_default();
+    if default_def
+        .prefix
+        .as_deref()
+        .is_some_and(|p| !p.trim_matches('/').is_empty())
+    {
+        return Err(eyre!(
+            "the default language `{default_code}` is served unprefixed; remove its `prefix`"
+        ));
+    }
+
+    let mut resolved = vec![Language {
+        name: default_def.name.unwrap_or_else(|| default_code.clone()),
+        code: default_code,
+        prefix: String::new(),
+    }];
+    let mut others: Vec<_> = defs.into_iter().collect();
+    others.sort_by(|a, b| a.0.cmp(&b.0));
+    for (code, def) in others {
+        let prefix = def.prefix.as_deref().unwrap_or(&code).trim_matches('/');
+        if prefix.is_empty() {
+            return Err(eyre!(
+                "language `{code}` needs a non-empty prefix; only the default language is unprefixed"
+            ));
+        }
+        let prefix = format!("/{prefix}");
+        if let Some(clash) = resolved.iter().find(|l| l.prefix == prefix) {
+            return Err(eyre!(
+                "languages `{}` and `{code}` share the prefix `{prefix}`",
+                clash.code
+            ));
+        }
+        resolved.push(Language {
+            name: def.name.unwrap_or_else(|| code.clone()),
+            code,
+            prefix,
+        });
+    }
+    Ok(resolved)
+}
+
 fn enforce_minimum_ddc_version(required: Option<&str>) -> Result<()> {
     let Some(required) = required else {
         return Ok(());
@@ -1015,6 +1110,40 @@ source {
         assert_eq!(sources[0].impls[0].include, vec!["picante/src/**/*.rs"]);
     }
 
+    #[test]
+    fn languages_resolve_with_default_first_and_unprefixed() {
+        let defs: std::collections::HashMap<String, LanguageDef> = [
+            (
+                "fr".to_string(),
+                LanguageDef {
+                    name: Some("Français".into()),
+                    prefix: None,
+                },
+            ),
+            (
+                "en".to_string(),
+                LanguageDef {
+                    name: Some("English".into()),
+                    prefix: None,
+                },
+            ),
+        ]
+        .into();
+        let languages = resolve_languages(None, Some(defs.clone())).unwrap();
+        let prefixes: Vec<_> = languages
+            .iter()
+            .map(|l| (l.code.as_str(), l.prefix.as_str()))
+            .collect();
+        assert_eq!(prefixes, [("en", ""), ("fr", "/fr")]);
+        assert_eq!(languages[1].name, "Français");
+
+        assert!(resolve_languages(None, None).unwrap().is_empty());
+
+        let mut prefixed_default = defs;
+        prefixed_default.get_mut("en").unwrap().prefix = Some("/en".into());
+        assert!(resolve_languages(Some("en"), Some(prefixed_default)).is_err());
+    }
+
     #[test]
     fn root_mount_path_is_rejected() {
         // `/` is the top-level `source`, not a `mounts` entry.
@@ -1038,6 +1167,13 @@ source {
             dark_theme_css: String::new(),
             page_types: None,
             auth: None,
+            taxonomies: Default::default(),
+            feed_content: FeedContent::default(),
+            feed_limit: None,
+            redirects: Default::default(),
+            permalinks: Default::default(),
+            languages: Vec::new(),
+            related: None,
         }
     }
 
@@ -39,6 +39,7 @@ impl ContentService for HostContentService {
             if auth_enabled && identity.is_none() {
                 return ServeContent::Redirect {
                     location: format!("/oauth2/start?rd={path}"),
+                    permanent: false,
                     generation,
                 };
             }
@@ -168,6 +169,7 @@ impl ContentService for HostContentService {
         if path == "/_dodeca/coverage" {
             return ServeContent::Redirect {
                 location: "/_dodeca/coverage/".to_string(),
+                permanent: false,
                 generation,
             };
         }
@@ -216,6 +218,7 @@ impl ContentService for HostContentService {
                     if auth_enabled && identity.is_none() {
                         return ServeContent::Redirect {
                             location: format!("/oauth2/start?rd={path}"),
+                            permanent: false,
                             generation,
                         };
                     }
@@ -270,6 +273,7 @@ impl ContentService for HostContentService {
             if let Some(location) = self.server.find_rule_redirect(rule_id).await {
                 return ServeContent::Redirect {
                     location,
+                    permanent: false,
                     generation,
                 };
             }
@@ -0,0 +1,99 @@
+//! Frontmatter date parsing.
+//!
+//! `date` and `updated` arrive from the markdown cell as the text the author
+//! wrote (TOML date literals are kept as strings). We accept the formats people
+//! actually type and normalise them to Unix seconds, the same unit as
+//! `last_updated`.
+
+use std::cmp::Ordering;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use facet::Facet;
+
+use crate::db::Page;
+
+/// Schema marker for date-valued frontmatter fields.
+///
+/// Only used in the frontmatter schema so the authoring LSP can tell dates
+/// apart from plain strings (TOML lets authors write them unquoted).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Facet)]
+#[facet(transparent)]
+pub struct FrontmatterDate(pub String);
+
+/// Parse a frontmatter date into Unix seconds (UTC).
+///
+/// Accepted forms:
+/// - `2024-05-01` (midnight UTC)
+/// - `2024-05-01T10:30:00` / `2024-05-01 10:30:00` (local time treated as UTC)
+/// - RFC 3339 with offset, e.g. `2024-05-01T10:30:00+02:00` or `...Z`
+pub fn parse_frontmatter_date(value: &str) -> Option<i64> {
+    let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+    if value.is_empty() {
+        return None;
+    }
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.timestamp());
+    }
+
+    for format in [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+    ] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(dt.and_utc().timestamp());
+        }
+    }
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc().timestamp())
+}
+
+/// Listing order for dated content (term pages, feeds): dated pages newest
+/// first, then undated ones by weight and title.
+pub fn newest_first(a: &Page, b: &Page) -> Ordering {
+    b.date
+        .cmp(&a.date)
+        .then(a.weight.cmp(&b.weight))
+        .then_with(|| a.title.as_str().cmp(b.title.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_frontmatter_date;
+
+    #[test]
+    fn parses_plain_dates_as_utc_midnight() {
+        assert_eq!(parse_frontmatter_date("2024-05-01"), Some(1_714_521_600));
+        assert_eq!(
+            parse_frontmatter_date("\"2024-05-01\""),
+            Some(1_714_521_600)
+        );
+    }
+
+    #[test]
+    fn parses_datetimes_with_and_without_offsets() {
+        assert_eq!(
+            parse_frontmatter_date("2024-05-01T10:00:00Z"),
+            Some(1_714_521_600 + 36_000)
+        );
+        assert_eq!(
+            parse_frontmatter_date("2024-05-01T12:00:00+02:00"),
+            Some(1_714_521_600 + 36_000)
+        );
+        assert_eq!(
+            parse_frontmatter_date("2024-05-01 10:00:00"),
+            Some(1_714_521_600 + 36_000)
+        );
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert_eq!(parse_frontmatter_date(""), None);
+        assert_eq!(parse_frontmatter_date("yesterday"), None);
+        assert_eq!(parse_frontmatter_date("2024-13-01"), None);
+    }
+}
@@ -207,6 +207,22 @@ pub struct MarkdownRenderSettings {
     pub render_notes: bool,
 }
 
+/// Settings that decide which sources are published.
+#[picante::input]
+pub struct PublishSettings {
+    /// Whether `draft = true` pages are part of the site tree. The dev server
+    /// previews drafts; production builds leave them out.
+    pub include_drafts: bool,
+}
+
+/// Wall-clock time that frontmatter `publish_at` embargoes are checked
+/// against. `ddc build` sets it once; the server advances it on a timer.
+#[picante::input]
+pub struct PublishClock {
+    /// Unix timestamp (seconds since epoch)
+    pub now: i64,
+}
+
 /// Interned character set for font subsetting
 /// Using a sorted Vec<char> for deterministic hashing
 #[picante::interned]
@@ -314,6 +330,20 @@ pub struct Section {
     pub head_injections: Vec<String>,
     /// Last modification time as Unix timestamp (seconds since epoch)
     pub last_updated: i64,
+    /// Frontmatter `date` as Unix timestamp
+    pub date: Option<i64>,
+    /// Frontmatter `updated` as Unix timestamp
+    pub updated: Option<i64>,
+    /// Frontmatter `draft` flag
+    pub draft: bool,
+    /// Frontmatter `paginate_by`: page listing size, if paginated
+    pub paginate_by: Option<u32>,
+    /// Frontmatter `sitemap` / `sitemap_priority`
+    pub sitemap: crate::sitemap::SitemapSettings,
+    /// Feeds published for this section's pages
+    pub feeds: Vec<crate::feeds::FeedFormat>,
+    /// Language code, when the site has `site.languages`
+    pub lang: Option<String>,
     /// Custom fields from the `[extra]` table in frontmatter
     pub extra: facet_value::Value,
     /// Custom template to use for rendering (overrides default)
@@ -338,17 +368,137 @@ pub struct Page {
     pub head_injections: Vec<String>,
     /// Last modification time as Unix timestamp (seconds since epoch)
     pub last_updated: i64,
+    /// Frontmatter `date` as Unix timestamp
+    pub date: Option<i64>,
+    /// Frontmatter `updated` as Unix timestamp
+    pub updated: Option<i64>,
+    /// Frontmatter `draft` flag
+    pub draft: bool,
+    /// Frontmatter `sitemap` / `sitemap_priority`
+    pub sitemap: crate::sitemap::SitemapSettings,
+    /// Frontmatter `[series]` membership
+    pub series: Option<SeriesMembership>,
+    /// Language code, when the site has `site.languages`
+    pub lang: Option<String>,
+    /// Terms per configured taxonomy, as the author wrote them
+    pub taxonomies: std::collections::BTreeMap<String, Vec<String>>,
     /// Custom fields from the `[extra]` table in frontmatter
     pub extra: facet_value::Value,
     /// Custom template to use for rendering (overrides default)
     pub template: Option<String>,
 }
 
+/// A configured taxonomy with the terms its pages use
+#[derive(Debug, Clone, PartialEq, Eq, Hash, facet::Facet)]
+pub struct Taxonomy {
+    /// Name as declared in `site { taxonomies {...} }` (e.g. "tags")
+    pub name: String,
+    /// Display title
+    pub title: Title,
+    /// Route of the index page listing every term
+    pub route: Route,
+    /// Terms keyed by slug
+    pub terms: std::collections::BTreeMap<String, TaxonomyTerm>,
+}
+
+/// One term of a taxonomy and the pages tagged with it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, facet::Facet)]
+pub struct TaxonomyTerm {
+    /// Term as first written by an author (e.g. "Rust")
+    pub name: String,
+    /// URL segment derived from the name (e.g. "rust")
+    pub slug: String,
+    /// Route of the term page
+    pub route: Route,
+    /// Tagged pages, newest first
+    pub pages: Vec<Route>,
+}
+
+/// A page of a paginated section listing after the first (`/blog/page/2`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, facet::Facet)]
+pub struct Pager {
+    /// The paginated section
+    pub section: Route,
+    /// Route of this pager
+    pub route: Route,
+    /// 1-based position; always 2 or more here, pager 1 is the section itself
+    pub index: u32,
+}
+
+/// A page's place in a series, from its `[series]` frontmatter table
+#[derive(Debug, Clone, PartialEq, Eq, Hash, facet::Facet)]
+pub struct SeriesMembership {
+    /// Series name, shared by every part (e.g. "Async Rust")
+    pub name: String,
+    /// Part number; parts are ordered by it, not by section or date
+    pub part: u32,
+}
+
+/// A named series of pages, possibly spread over several sections
+#[derive(Debug, Clone, PartialEq, Eq, Hash, facet::Facet)]
+pub struct Series {
+    /// Series name as written in frontmatter
+    pub name: String,
+    /// Member pages ordered by part number
+    pub pages: Vec<Route>,
+}
+
+/// One language version of a translated page or section
+#[derive(Debug, Clone, PartialEq, Eq, Hash, facet::Facet)]
+pub struct Translation {
+    /// Language code (e.g. "fr")
+    pub lang: String,
+    /// Route of this version
+    pub route: Route,
+}
+
 /// The complete site tree - sections and pages
 #[derive(Debug, Clone, PartialEq, Eq, facet::Facet)]
 pub struct SiteTree {
     pub sections: std::collections::BTreeMap<Route, Section>,
     pub pages: std::collections::BTreeMap<Route, Page>,
+    /// Extra pagers of paginated sections, keyed by route
+    pub pagers: std::collections::BTreeMap<Route, Pager>,
+    /// Configured taxonomies keyed by name
+    pub taxonomies: std::collections::BTreeMap<String, Taxonomy>,
+    /// Page series keyed by name
+    pub series: std::collections::BTreeMap<String, Series>,
+    /// Every language version of translated content, keyed by the route of
+    /// each version
+    pub translations: std::collections::BTreeMap<Route, Vec<Translation>>,
+    /// Permanent redirects from page `aliases` and `site.redirects`: old
+    /// route to target route or URL
+    pub redirects: std::collections::BTreeMap<Route, String>,
+}
+
+impl SiteTree {
+    /// Routes of the generated taxonomy index and term pages. Content at the
+    /// same route takes precedence, so those are left out.
+    pub fn taxonomy_routes(&self) -> impl Iterator<Item = &Route> {
+        self.taxonomies
+            .values()
+            .flat_map(|t| std::iter::once(&t.route).chain(t.terms.values().map(|term| &term.route)))
+            .filter(|route| {
+                !self.sections.contains_key(*route)
+                    && !self.pages.contains_key(*route)
+                    && !self.pagers.contains_key(*route)
+            })
+    }
+
+    /// Every route rendered as HTML: sections, pages, section pagers, then
+    /// taxonomy pages.
+    pub fn html_routes(&self) -> impl Iterator<Item = &Route> {
+        self.sections
+            .keys()
+            .chain(self.pages.keys())
+            .chain(self.pagers.keys())
+            .chain(self.taxonomy_routes())
+    }
+
+    /// Every route a link may point at: rendered routes plus redirected ones.
+    pub fn link_targets(&self) -> impl Iterator<Item = &Route> {
+        self.html_routes().chain(self.redirects.keys())
+    }
 }
 
 /// Rendered HTML output for a page or section
@@ -393,6 +543,31 @@ pub struct ParsedData {
     pub head_injections: Vec<String>,
     /// Last modification time as Unix timestamp (seconds since epoch)
     pub last_updated: i64,
+    /// Frontmatter `date` as Unix timestamp
+    pub date: Option<i64>,
+    /// Frontmatter `updated` as Unix timestamp
+    pub updated: Option<i64>,
+    /// Frontmatter `draft` flag
+    pub draft: bool,
+    /// Frontmatter `publish_at` as Unix timestamp
+    pub publish_at: Option<i64>,
+    /// Frontmatter `paginate_by` (sections only)
+    pub paginate_by: Option<u32>,
+    /// Frontmatter `feeds` (sections only)
+    pub feeds: Vec<crate::feeds::FeedFormat>,
+    /// Frontmatter `sitemap` / `sitemap_priority`
+    pub sitemap: crate::sitemap::SitemapSettings,
+    /// Frontmatter `related`: `false` opts the page out of related pages
+    pub related: bool,
+    /// Frontmatter `aliases`: former routes redirected to this one
+    pub aliases: Vec<Route>,
+    /// Frontmatter `[series]` membership (pages only)
+    pub series: Option<SeriesMembership>,
+    /// Frontmatter `[taxonomies]` table: taxonomy name to terms
+    pub taxonomies: std::collections::BTreeMap<String, Vec<String>>,
+    /// Language code from the file name (`intro.fr.md`), when the site has
+    /// `site.languages`
+    pub lang: Option<String>,
     /// Custom fields from the `[extra]` table in frontmatter
     pub extra: facet_value::Value,
     /// Custom template to use for rendering (overrides default)
@@ -608,6 +783,8 @@ pub struct AllRenderedHtml {
         IncludedFileRegistry,
         ConfigRegistry,
         MarkdownRenderSettings,
+        PublishSettings,
+        PublishClock,
     ),
     interned(CharSet, crate::queries::DataValuePath,),
     tracked(
@@ -629,6 +806,12 @@ pub struct AllRenderedHtml {
         crate::queries::render_page,
         crate::queries::render_page_markdown,
         crate::queries::render_section,
+        crate::queries::render_section_pager,
+        crate::taxonomy::taxonomy_render_tree,
+        crate::taxonomy::taxonomy_index,
+        crate::taxonomy::taxonomy_term,
+        crate::taxonomy::render_taxonomy,
+        crate::taxonomy::render_taxonomy_term,
         crate::queries::load_static,
         crate::queries::optimize_svg,
         crate::queries::load_all_static,
@@ -655,6 +838,15 @@ pub struct AllRenderedHtml {
         crate::queries::serve_html,
         crate::queries::check_external_url,
         crate::search::search_index_files,
+        crate::feeds::feed_files,
+        crate::sitemap::sitemap_files,
+        crate::redirects::redirect_files,
+        crate::schedule::embargoed_sources,
+        crate::i18n::site_languages,
+        crate::permalinks::site_permalinks,
+        crate::related::site_related,
+        crate::related::page_vectors,
+        crate::related::related_pages,
         crate::knowledge::page_chunks_embedded,
         crate::authoring_graph::authoring_project,
         crate::authoring_graph::content_graph,
@@ -0,0 +1,531 @@
+//! Section feeds: Atom, RSS 2.0 and JSON Feed.
+//!
+//! A section opts in with `feeds = ["atom", "rss", "json"]` in its
+//! frontmatter and gets `atom.xml`, `rss.xml` and/or `feed.json` next to its
+//! index, listing its pages newest first. [`feed_files`] is a tracked query
+//! shared by `build_site` (written to disk) and `ddc serve` (served live), the
+//! same way the search index is.
+
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use facet::Facet;
+use facet_value::DestructuredRef;
+use picante::PicanteResult;
+
+use crate::cells::MarkdownParseError;
+use crate::config::FeedContent;
+use crate::dates::newest_first;
+use crate::db::{Db, OutputFile, Page, Section, SiteTree};
+use crate::queries::{build_tree, source_to_route_map};
+use crate::types::{Route, StaticPath};
+
+/// A feed format a section can publish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Facet)]
+#[repr(u8)]
+pub enum FeedFormat {
+    Atom,
+    Rss,
+    Json,
+}
+
+impl FeedFormat {
+    /// Parse a frontmatter `feeds` entry.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "atom" => Some(FeedFormat::Atom),
+            "rss" => Some(FeedFormat::Rss),
+            "json" => Some(FeedFormat::Json),
+            _ => None,
+        }
+    }
+
+    /// File name of the feed inside its section.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "atom.xml",
+            FeedFormat::Rss => "rss.xml",
+            FeedFormat::Json => "feed.json",
+        }
+    }
+
+    /// MIME type, used for `<link rel="alternate">` and when serving.
+    pub fn mime(self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "application/atom+xml",
+            FeedFormat::Rss => "application/rss+xml",
+            FeedFormat::Json => "application/feed+json",
+        }
+    }
+}
+
+/// Read the frontmatter `feeds` list, keeping the author's order and dropping
+/// duplicates.
+pub fn parse_feed_formats(names: &[String]) -> Result<Vec<FeedFormat>, MarkdownParseError> {
+    let mut formats = Vec::new();
+    for name in names {
+        let format = FeedFormat::parse(name).ok_or_else(|| MarkdownParseError {
+            message: format!(
+                "invalid frontmatter feeds entry '{name}': expected atom, rss or json"
+            ),
+        })?;
+        if !formats.contains(&format) {
+            formats.push(format);
+        }
+    }
+    Ok(formats)
+}
+
+/// Output path of a section's feed, without a leading slash (`blog/atom.xml`).
+pub fn feed_path(section: &Route, format: FeedFormat) -> String {
+    let base = section.as_str().trim_matches('/');
+    if base.is_empty() {
+        format.file_name().to_string()
+    } else {
+        format!("{base}/{}", format.file_name())
+    }
+}
+
+/// The feed format a request path (without leading slash) names, if any.
+/// Lets `ddc serve` skip feed generation for everything else.
+pub fn format_for_path(rel: &str) -> Option<FeedFormat> {
+    let name = rel.rsplit('/').next().unwrap_or(rel);
+    [FeedFormat::Atom, FeedFormat::Rss, FeedFormat::Json]
+        .into_iter()
+        .find(|format| format.file_name() == name)
+}
+
+/// `<link rel="alternate">` tags advertising the feeds of `section`, for the
+/// `<head>` of the section and of every page in it.
+pub fn alternate_links(tree: &SiteTree, section: &Route) -> Vec<String> {
+    let Some(section) = tree.sections.get(section) else {
+        return Vec::new();
+    };
+    let base_url = crate::render::get_base_url();
+    section
+        .feeds
+        .iter()
+        .map(|format| {
+            format!(
+                r#"<link rel="alternate" type="{}" title="{}" href="{}">"#,
+                format.mime(),
+                escape(section.title.as_str()),
+                escape(&absolute_url(
+                    &base_url,
+                    &feed_path(&section.route, *format)
+                )),
+            )
+        })
+        .collect()
+}
+
+/// Every feed of every section that asked for one.
+///
+/// Tracked: re-runs when the tree, the config or a linked source changes. Tree errors are
+/// surfaced by `build_site`; here they just mean no feeds.
+#[picante::tracked]
+pub async fn feed_files<DB: Db>(db: &DB) -> PicanteResult<Vec<OutputFile>> {
+    let tree = match build_tree(db).await? {
+        Ok(tree) => tree,
+        Err(_) => return Ok(Vec::new()),
+    };
+    let sections: Vec<&Section> = tree
+        .sections
+        .values()
+        .filter(|s| !s.feeds.is_empty())
+        .collect();
+    if sections.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let config = crate::db::ConfigRegistry::config(db)?;
+    let (content_mode, limit) = config
+        .as_ref()
+        .map(|c| (c.feed_content, c.feed_limit))
+        .unwrap_or_default();
+    let base_url = config.map_or_else(|| "/".to_string(), |c| c.base_url.clone());
+    let source_route_map = source_to_route_map(db).await?;
+
+    let mut files = Vec::new();
+    for section in sections {
+        let feed = Feed::build(
+            section,
+            &tree,
+            content_mode,
+            limit,
+            &base_url,
+            &source_route_map,
+        )
+        .await;
+        for &format in &section.feeds {
+            let path = feed_path(&section.route, format);
+            let self_url = absolute_url(&base_url, &path);
+            let content = match format {
+                FeedFormat::Atom => feed.to_atom(&self_url),
+                FeedFormat::Rss => feed.to_rss(&self_url),
+                FeedFormat::Json => feed.to_json(&self_url),
+            };
+            files.push(OutputFile::Static {
+                path: StaticPath::new(path),
+                content: content.into_bytes(),
+            });
+        }
+    }
+    Ok(files)
+}
+
+/// A section's feed, format-independent.
+struct Feed {
+    title: String,
+    description: Option<String>,
+    home_url: String,
+    /// Unix seconds: the newest entry, or the section itself when empty
+    updated: i64,
+    entries: Vec<FeedEntry>,
+}
+
+struct FeedEntry {
+    title: String,
+    url: String,
+    published: Option<i64>,
+    updated: i64,
+    /// Absolute-linked HTML: the full body or the summary, per `feeds.content`
+    content_html: String,
+    summary_html: Option<String>,
+}
+
+impl Feed {
+    async fn build(
+        section: &Section,
+        tree: &SiteTree,
+        content_mode: FeedContent,
+        limit: Option<usize>,
+        base_url: &str,
+        source_route_map: &HashMap<String, String>,
+    ) -> Self {
+        use crate::url_rewrite::{resolve_internal_links, resolve_relative_links};
+
+        let mut pages: Vec<&Page> = tree
+            .pages
+            .values()
+            .filter(|p| p.section_route == section.route)
+            .collect();
+        pages.sort_by(|a, b| newest_first(a, b));
+        if let Some(limit) = limit {
+            pages.truncate(limit);
+        }
+
+        let mut entries = Vec::with_capacity(pages.len());
+        for page in pages {
+            let summary = summary_html(page);
+            let html = match content_mode {
+                FeedContent::Full => page.body_html.as_str().to_string(),
+                FeedContent::Summary => summary.clone(),
+            };
+            let html = resolve_relative_links(&html, page.section_route.as_str()).await;
+            let html = resolve_internal_links(&html, source_route_map).await;
+            entries.push(FeedEntry {
+                title: page.title.as_str().to_string(),
+                url: absolute_url(base_url, page.route.as_str()),
+                published: page.date,
+                updated: page.updated.or(page.date).unwrap_or(page.last_updated),
+                content_html: absolutize_links(&html, base_url),
+                summary_html: (content_mode == FeedContent::Full && !summary.is_empty())
+                    .then(|| absolutize_links(&summary, base_url)),
+            });
+        }
+
+        let updated = entries.iter().map(|e| e.updated).max().unwrap_or_else(|| {
+            section
+                .updated
+                .or(section.date)
+                .unwrap_or(section.last_updated)
+        });
+
+        Feed {
+            title: section.title.as_str().to_string(),
+            description: section.description.clone(),
+            home_url: absolute_url(base_url, section.route.as_str()),
+            updated,
+            entries,
+        }
+    }
+
+    fn to_atom(&self, self_url: &str) -> String {
+        let mut out = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+        out.push_str("\n<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+        push_element(&mut out, "  ", "title", &self.title);
+        if let Some(description) = &self.description {
+            push_element(&mut out, "  ", "subtitle", description);
+        }
+        out.push_str(&format!(
+            "  <link href=\"{}\"/>\n  <link rel=\"self\" type=\"{}\" href=\"{}\"/>\n",
+            escape(&self.home_url),
+            FeedFormat::Atom.mime(),
+            escape(self_url),
+        ));
+        push_element(&mut out, "  ", "updated", &rfc3339(self.updated));
+        push_element(&mut out, "  ", "id", &self.home_url);
+        for entry in &self.entries {
+            out.push_str("  <entry>\n");
+            push_element(&mut out, "    ", "title", &entry.title);
+            out.push_str(&format!("    <link href=\"{}\"/>\n", escape(&entry.url)));
+            push_element(&mut out, "    ", "id", &entry.url);
+            if let Some(published) = entry.published {
+                push_element(&mut out, "    ", "published", &rfc3339(published));
+            }
+            push_element(&mut out, "    ", "updated", &rfc3339(entry.updated));
+            if let Some(summary) = &entry.summary_html {
+                out.push_str(&format!(
+                    "    <summary type=\"html\">{}</summary>\n",
+                    escape(summary)
+                ));
+            }
+            out.push_str(&format!(
+                "    <content type=\"html\">{}</content>\n",
+                escape(&entry.content_html)
+            ));
+            out.push_str("  </entry>\n");
+        }
+        out.push_str("</feed>\n");
+        out
+    }
+
+    fn to_rss(&self, self_url: &str) -> String {
+        let mut out = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+        out.push_str(
+            "\n<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">\n<channel>\n",
+        );
+        push_element(&mut out, "  ", "title", &self.title);
+        push_element(&mut out, "  ", "link", &self.home_url);
+        push_element(
+            &mut out,
+            "  ",
+            "description",
+            self.description.as_deref().unwrap_or(&self.title),
+        );
+        out.push_str(&format!(
+            "  <atom:link href=\"{}\" rel=\"self\" type=\"{}\"/>\n",
+            escape(self_url),
+            FeedFormat::Rss.mime(),
+        ));
+        push_element(&mut out, "  ", "lastBuildDate", &rfc2822(self.updated));
+        for entry in &self.entries {
+            out.push_str("  <item>\n");
+            push_element(&mut out, "    ", "title", &entry.title);
+            push_element(&mut out, "    ", "link", &entry.url);
+            out.push_str(&format!(
+                "    <guid isPermaLink=\"true\">{}</guid>\n",
+                escape(&entry.url)
+            ));
+            push_element(
+                &mut out,
+                "    ",
+                "pubDate",
+                &rfc2822(entry.published.unwrap_or(entry.updated)),
+            );
+            push_element(&mut out, "    ", "description", &entry.content_html);
+            out.push_str("  </item>\n");
+        }
+        out.push_str("</channel>\n</rss>\n");
+        out
+    }
+
+    fn to_json(&self, self_url: &str) -> String {
+        let feed = JsonFeed {
+            version: "https://jsonfeed.org/version/1.1",
+            title: self.title.clone(),
+            home_page_url: self.home_url.clone(),
+            feed_url: self_url.to_string(),
+            description: self.description.clone(),
+            items: self
+                .entries
+                .iter()
+                .map(|entry| JsonFeedItem {
+                    id: entry.url.clone(),
+                    url: entry.url.clone(),
+                    title: entry.title.clone(),
+                    content_html: entry.content_html.clone(),
+                    summary: entry.summary_html.clone(),
+                    date_published: entry.published.map(rfc3339),
+                    date_modified: rfc3339(entry.updated),
+                })
+                .collect(),
+        };
+        facet_json::to_string(&feed).unwrap_or_else(|_| "{}".to_string())
+    }
+}
+
+/// JSON Feed 1.1 document (<https://jsonfeed.org/version/1.1>)
+#[derive(Facet)]
+struct JsonFeed {
+    version: &'static str,
+    title: String,
+    home_page_url: String,
+    feed_url: String,
+    #[facet(skip_serializing_if = Option::is_none)]
+    description: Option<String>,
+    items: Vec<JsonFeedItem>,
+}
+
+#[derive(Facet)]
+struct JsonFeedItem {
+    id: String,
+    url: String,
+    title: String,
+    content_html: String,
+    #[facet(skip_serializing_if = Option::is_none)]
+    summary: Option<String>,
+    #[facet(skip_serializing_if = Option::is_none)]
+    date_published: Option<String>,
+    date_modified: String,
+}
+
+/// A page's summary: `extra.description` when set, otherwise the first
+/// paragraph of its body.
+fn summary_html(page: &Page) -> String {
+    if let DestructuredRef::Object(extra) = page.extra.destructure_ref()
+        && let Some(description) = extra.get("description").and_then(|d| d.as_string())
+    {
+        return format!("<p>{}</p>", escape(description.as_str()));
+    }
+    let body = page.body_html.as_str();
+    body.find("<p")
+        .and_then(|start| {
+            let end = body[start..].find("</p>")? + start + "</p>".len();
+            Some(body[start..end].to_string())
+        })
+        .unwrap_or_default()
+}
+
+fn absolute_url(base_url: &str, path: &str) -> String {
+    let base = base_url.trim_end_matches('/');
+    let path = path.trim_start_matches('/');
+    if path.is_empty() {
+        format!("{base}/")
+    } else {
+        format!("{base}/{path}")
+    }
+}
+
+/// Point root-relative `href`/`src` attributes at `base_url`, since feed
+/// readers have no page URL to resolve them against.
+fn absolutize_links(html: &str, base_url: &str) -> String {
+    let base = base_url.trim_end_matches('/');
+    if base.is_empty() {
+        return html.to_string();
+    }
+    let mut out = html.to_string();
+    for attr in ["href", "src"] {
+        let needle = format!("{attr}=\"/");
+        let mut result = String::with_capacity(out.len());
+        let mut rest = out.as_str();
+        while let Some(pos) = rest.find(&needle) {
+            let after = &rest[pos + needle.len()..];
+            result.push_str(&rest[..pos]);
+            if after.starts_with('/') {
+                // Protocol-relative URL: leave it alone.
+                result.push_str(&needle);
+            } else {
+                result.push_str(&format!("{attr}=\"{base}/"));
+            }
+            rest = after;
+        }
+        result.push_str(rest);
+        out = result;
+    }
+    out
+}
+
+fn push_element(out: &mut String, indent: &str, name: &str, text: &str) {
+    out.push_str(&format!("{indent}<{name}>{}</{name}>\n", escape(text)));
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn rfc3339(secs: i64) -> String {
+    DateTime::from_timestamp(secs, 0)
+        .unwrap_or_default()
+        .to_rfc3339()
+}
+
+fn rfc2822(secs: i64) -> String {
+    DateTime::from_timestamp(secs, 0)
+        .unwrap_or_default()
+        .to_rfc2822()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed() -> Feed {
+        Feed {
+            title: "Blog & News".to_string(),
+            description: None,
+            home_url: "https://example.com/blog".to_string(),
+            updated: 1_714_521_600,
+            entries: vec![FeedEntry {
+                title: "Hello".to_string(),
+                url: "https://example.com/blog/hello".to_string(),
+                published: Some(1_714_521_600),
+                updated: 1_714_521_600,
+                content_html: "<p>Hi <a href=\"https://example.com/about\">there</a></p>"
+                    .to_string(),
+                summary_html: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn parse_feed_formats_rejects_unknown_and_dedups() {
+        let names = ["atom", "RSS", "atom"].map(String::from);
+        assert_eq!(
+            parse_feed_formats(&names).unwrap(),
+            [FeedFormat::Atom, FeedFormat::Rss]
+        );
+        assert!(parse_feed_formats(&["xml".to_string()]).is_err());
+    }
+
+    #[test]
+    fn feed_paths_sit_next_to_the_section() {
+        let blog = Route::new("/blog".to_string());
+        assert_eq!(feed_path(&blog, FeedFormat::Atom), "blog/atom.xml");
+        assert_eq!(feed_path(&Route::root(), FeedFormat::Json), "feed.json");
+        assert_eq!(format_for_path("blog/rss.xml"), Some(FeedFormat::Rss));
+        assert_eq!(format_for_path("blog/post"), None);
+    }
+
+    #[test]
+    fn absolutize_links_rewrites_root_relative_urls_only() {
+        let html = r#"<a href="/a">a</a><img src="/i.png"><a href="//cdn/x">x</a><a href="https://o/">o</a>"#;
+        assert_eq!(
+            absolutize_links(html, "https://example.com/"),
+            r#"<a href="https://example.com/a">a</a><img src="https://example.com/i.png"><a href="//cdn/x">x</a><a href="https://o/">o</a>"#
+        );
+        assert_eq!(absolutize_links(html, "/"), html);
+    }
+
+    #[test]
+    fn atom_escapes_titles_and_content() {
+        let atom = feed().to_atom("https://example.com/blog/atom.xml");
+        assert!(atom.contains("<title>Blog &amp; News</title>"));
+        assert!(atom.contains("<updated>2024-05-01T00:00:00+00:00</updated>"));
+        assert!(atom.contains(
+            "<content type=\"html\">&lt;p&gt;Hi &lt;a href=&quot;https://example.com/about&quot;&gt;"
+        ));
+        assert!(atom.contains(r#"<link rel="self" type="application/atom+xml" href="https://example.com/blog/atom.xml"/>"#));
+    }
+
+    #[test]
+    fn rss_uses_rfc2822_dates() {
+        let rss = feed().to_rss("https://example.com/blog/rss.xml");
+        assert!(rss.contains("<pubDate>Wed, 1 May 2024 00:00:00 +0000</pubDate>"));
+        assert!(rss.contains("<guid isPermaLink=\"true\">https://example.com/blog/hello</guid>"));
+    }
+}
@@ -128,6 +128,10 @@ pub fn scan_directory_recursive(dir: &Path, config: &WatcherConfig) -> Vec<FileE
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PathCategory {
     Content,
+    /// A non-markdown file in a content tree. Inside a page bundle (a
+    /// directory holding `index.md`) it is one of the bundle's co-located
+    /// assets and lives in the static registry under its content path.
+    BundleAsset,
     Template,
     Sass,
     Static,
@@ -162,6 +166,52 @@ impl WatcherConfig {
             .map(|(s, rel)| (s.mount.clone(), rel))
     }
 
+    /// The content dir owning a content-tree path.
+    fn content_root(&self, path: &Utf8Path) -> Option<Utf8PathBuf> {
+        if self.sources.is_empty() {
+            return path
+                .starts_with(&self.content_dir)
+                .then(|| self.content_dir.clone());
+        }
+        longest_source_match(&self.sources, path, |s| Some(s.content_dir.clone()))
+            .map(|(s, _)| s.content_dir.clone())
+    }
+
+    /// Whether a [`PathCategory::BundleAsset`] path currently belongs to a page
+    /// bundle (some directory above it, below the content root, has an
+    /// `index.md`).
+    pub fn in_bundle(&self, path: &Utf8Path) -> bool {
+        let Some(root) = self.content_root(path) else {
+            return false;
+        };
+        path.strip_prefix(&root)
+            .is_ok_and(|rel| crate::build_context::bundle_dir(&root, rel).is_some())
+    }
+
+    /// Whether the static-registry entry `key` still has a file behind it: a
+    /// bundle asset in a content tree, or a file in a `static/` or `dist/`
+    /// tree. Used to drop the assets of a bundle that was moved or dissolved.
+    pub fn static_key_on_disk(&self, key: &str) -> bool {
+        let content_path = if self.sources.is_empty() {
+            Some(self.content_dir.join(key))
+        } else {
+            crate::build_context::source_for_key(&self.sources, key)
+                .map(|(s, rel)| s.content_dir.join(rel))
+        };
+        if content_path.is_some_and(|p| p.is_file() && self.in_bundle(&p)) {
+            return true;
+        }
+        if self.static_dir.join(key).is_file() || self.dist_dir.join(key).is_file() {
+            return true;
+        }
+        crate::build_context::source_for_key(&self.sources, key).is_some_and(|(s, rel)| {
+            source_static_dir(&s).is_some_and(|dir| dir.join(&rel).is_file())
+                || s.content_dir
+                    .parent()
+                    .is_some_and(|p| p.join("dist").join(&rel).is_file())
+        })
+    }
+
     /// The source-relative `(mount, rel)` for a static-tree path.
     fn static_match(&self, path: &Utf8Path) -> Option<(String, Utf8PathBuf)> {
         if self.sources.is_empty() {
@@ -209,7 +259,11 @@ impl WatcherConfig {
         } else if self.code_files.contains(path) {
             PathCategory::Code
         } else if self.content_match(path).is_some() {
-            PathCategory::Content
+            if path.extension() == Some("md") || path.is_dir() {
+                PathCategory::Content
+            } else {
+                PathCategory::BundleAsset
+            }
         } else if self.template_match(path).is_some() {
             PathCategory::Template
         } else if self.sass_match(path).is_some() {
@@ -229,7 +283,7 @@ impl WatcherConfig {
     /// keys are mount-prefixed (`spec/build/…`) so they match `BuildContext`.
     pub fn relative_path(&self, path: &Utf8Path) -> Option<Utf8PathBuf> {
         match self.categorize(path) {
-            PathCategory::Content => self
+            PathCategory::Content | PathCategory::BundleAsset => self
                 .content_match(path)
                 .map(|(mount, rel)| crate::build_context::mounted_key(&mount, rel.as_str()).into()),
             PathCategory::Static => self
@@ -309,6 +363,7 @@ fn should_watch_path(path: &Path, config: &WatcherConfig) -> bool {
     match config.categorize(utf8_path) {
         PathCategory::Config | PathCategory::Include | PathCategory::Code => true,
         PathCategory::Static | PathCategory::Dist | PathCategory::Data => true,
+        PathCategory::BundleAsset => config.in_bundle(utf8_path),
         PathCategory::Content | PathCategory::Template | PathCategory::Sass => {
             // For these, check extension
             path.extension()
@@ -785,6 +840,35 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_bundle_assets() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = Utf8Path::from_path(tmp.path()).unwrap();
+        std::fs::create_dir_all(base.join("content/article/img")).unwrap();
+        std::fs::write(base.join("content/article/index.md"), "+++\n+++\n").unwrap();
+        std::fs::write(base.join("content/article/img/cover.jpg"), b"jpg").unwrap();
+        std::fs::write(base.join("content/notes.txt"), b"txt").unwrap();
+        let config = test_config(base);
+
+        let cover = base.join("content/article/img/cover.jpg");
+        assert_eq!(config.categorize(&cover), PathCategory::BundleAsset);
+        assert_eq!(
+            config.relative_path(&cover),
+            Some(Utf8PathBuf::from("article/img/cover.jpg"))
+        );
+        assert!(should_watch_path(cover.as_std_path(), &config));
+        assert!(!should_watch_path(
+            base.join("content/notes.txt").as_std_path(),
+            &config
+        ));
+        assert!(config.static_key_on_disk("article/img/cover.jpg"));
+
+        // Moving the bundle away leaves nothing behind its old keys.
+        std::fs::rename(base.join("content/article"), base.join("content/moved")).unwrap();
+        assert!(!config.static_key_on_disk("article/img/cover.jpg"));
+        assert!(config.static_key_on_disk("moved/img/cover.jpg"));
+    }
+
     #[test]
     fn test_should_watch_static_files() {
         let base = Utf8Path::new("/project");
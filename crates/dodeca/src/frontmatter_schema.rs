@@ -102,7 +102,7 @@ fn schema_file(page_types: &HashMap<String, PageTypeSchema>) -> SchemaFile {
     }
 }
 
-fn frontmatter_type(extra: &Value) -> Option<&str> {
+pub(crate) fn frontmatter_type(extra: &Value) -> Option<&str> {
     extra
         .as_object()?
         .get("type")?
@@ -157,8 +157,11 @@ fn slug_keys(data: &ParsedData) -> Vec<String> {
         .to_string();
     if source_key == "_index" {
         source_key.clear();
-    } else if let Some(section_key) = source_key.strip_suffix("/_index") {
-        source_key = section_key.to_string();
+    } else if let Some(dir_key) = source_key
+        .strip_suffix("/_index")
+        .or_else(|| source_key.strip_suffix("/index"))
+    {
+        source_key = dir_key.to_string();
     }
     if !source_key.is_empty() {
         keys.insert(source_key.clone());
@@ -179,7 +182,10 @@ fn normalize_slug(slug: &str) -> String {
     if let Some(stripped) = slug.strip_suffix(".md") {
         slug = stripped;
     }
-    if let Some(stripped) = slug.strip_suffix("/_index") {
+    if let Some(stripped) = slug
+        .strip_suffix("/_index")
+        .or_else(|| slug.strip_suffix("/index"))
+    {
         slug = stripped;
     }
     slug.to_string()
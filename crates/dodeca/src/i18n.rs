@@ -0,0 +1,269 @@
+//! Translated content.
+//!
+//! With `site.languages` configured, `intro.fr.md` is the `fr` translation of
+//! `intro.md`: it is served under the language's route prefix (`/fr/intro`),
+//! listed only among `fr` content, and tied to its other versions through
+//! [`SiteTree::translations`], which templates see as `page.translations` and
+//! the `<head>` as `hreflang` alternates. Without `site.languages` a `.fr.md`
+//! file is an ordinary page.
+
+use std::collections::{BTreeMap, HashMap};
+
+use picante::PicanteResult;
+
+use crate::config::Language;
+use crate::db::{ConfigRegistry, Db, ParsedData, SiteTree, Translation};
+use crate::types::Route;
+
+/// The configured languages, default first. Empty for a monolingual site.
+///
+/// Tracked on the config input so routes follow a `site.languages` edit in
+/// every mode, while edits to the rest of the config stop here instead of
+/// re-parsing every file.
+#[picante::tracked]
+pub async fn site_languages<DB: Db>(db: &DB) -> PicanteResult<Vec<Language>> {
+    Ok(ConfigRegistry::config(db)?
+        .map(|c| c.languages.clone())
+        .unwrap_or_default())
+}
+
+/// [`site_languages`] for render helpers that have no database at hand.
+pub fn languages() -> Vec<Language> {
+    crate::config::global_config()
+        .map(|c| c.languages.clone())
+        .unwrap_or_default()
+}
+
+/// Split a source path into its language and the path of the untranslated
+/// original: `guide/_index.fr.md` → (`fr`, `guide/_index.md`). Paths without
+/// a language suffix belong to the default language. `None` when the site is
+/// monolingual.
+pub fn split_source_path<'a>(
+    path: &str,
+    languages: &'a [Language],
+) -> Option<(&'a Language, String)> {
+    let default = languages.first()?;
+    if let Some((base, code)) = path
+        .strip_suffix(".md")
+        .and_then(|stem| stem.rsplit_once('.'))
+        && !base.is_empty()
+        && !base.ends_with('/')
+        && let Some(lang) = languages.iter().find(|l| l.code == code)
+    {
+        return Some((lang, format!("{base}.md")));
+    }
+    Some((default, path.to_string()))
+}
+
+/// Put `route` under a language prefix: (`/guide`, `/fr`) → `/fr/guide`, and
+/// the root becomes the language home (`/fr`).
+pub fn localize(route: &Route, prefix: &str) -> Route {
+    if prefix.is_empty() {
+        route.clone()
+    } else if route.as_str() == "/" {
+        Route::new(prefix.to_string())
+    } else {
+        Route::new(format!("{prefix}{}", route.as_str()))
+    }
+}
+
+/// The language a route is served in, by prefix; unprefixed routes are in
+/// the default language.
+pub fn language_of_route<'a>(route: &str, languages: &'a [Language]) -> Option<&'a Language> {
+    languages
+        .iter()
+        .skip(1)
+        .find(|l| {
+            route
+                .strip_prefix(l.prefix.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+        .or(languages.first())
+}
+
+/// The language of a route or of a source path (`guide/intro.fr.md`).
+pub fn language_of<'a>(path: &str, languages: &'a [Language]) -> Option<&'a Language> {
+    if path.ends_with(".md") {
+        split_source_path(path, languages).map(|(lang, _)| lang)
+    } else {
+        language_of_route(path, languages)
+    }
+}
+
+/// Drop the language prefix from a route: `/fr/guide` → `/guide`, `/fr` → `/`.
+pub fn strip_language_prefix<'a>(route: &'a str, languages: &[Language]) -> &'a str {
+    match language_of_route(route, languages) {
+        Some(lang) if !lang.prefix.is_empty() => match &route[lang.prefix.len()..] {
+            "" => "/",
+            rest => rest,
+        },
+        _ => route,
+    }
+}
+
+/// Whether `route` is the content root or a language home (`/fr`).
+pub fn is_home(route: &str, languages: &[Language]) -> bool {
+    route == "/" || languages.iter().any(|l| l.prefix == route)
+}
+
+/// The `@/` link map as seen from a page in `lang`: links to a source that
+/// has a `lang` translation land on the translation.
+pub fn localized_source_map(
+    source_to_route: &HashMap<String, String>,
+    lang: &str,
+    languages: &[Language],
+) -> HashMap<String, String> {
+    let mut map = source_to_route.clone();
+    for (source, route) in source_to_route {
+        if let Some((source_lang, original)) = split_source_path(source, languages)
+            && source_lang.code == lang
+        {
+            map.insert(original, route.clone());
+        }
+    }
+    map
+}
+
+/// Every language version of each translated page or section, keyed by the
+/// route of each version and ordered like `site.languages`. Content that
+/// exists in a single language is left out.
+pub fn collect(parsed: &[ParsedData], languages: &[Language]) -> BTreeMap<Route, Vec<Translation>> {
+    let mut versions: BTreeMap<String, Vec<(usize, Translation)>> = BTreeMap::new();
+    for data in parsed {
+        let Some((lang, original)) = split_source_path(data.source_path.as_str(), languages) else {
+            continue;
+        };
+        let order = languages.iter().position(|l| l.code == lang.code);
+        versions.entry(original).or_default().push((
+            order.unwrap_or(usize::MAX),
+            Translation {
+                lang: lang.code.clone(),
+                route: data.route.clone(),
+            },
+        ));
+    }
+
+    let mut translations = BTreeMap::new();
+    for mut group in versions.into_values().filter(|g| g.len() > 1) {
+        group.sort_by_key(|(order, _)| *order);
+        let group: Vec<Translation> = group.into_iter().map(|(_, t)| t).collect();
+        for version in &group {
+            translations.insert(version.route.clone(), group.clone());
+        }
+    }
+    translations
+}
+
+/// `<link rel="alternate" hreflang>` tags for every version of `route`, itself
+/// included, plus `x-default` pointing at the default-language version.
+pub fn alternate_links(tree: &SiteTree, route: &Route) -> Vec<String> {
+    let Some(versions) = tree.translations.get(route) else {
+        return Vec::new();
+    };
+    let base_url = crate::render::get_base_url();
+    let default_code = languages().first().map(|l| l.code.clone());
+    let link = |hreflang: &str, route: &Route| {
+        format!(
+            r#"<link rel="alternate" hreflang="{hreflang}" href="{}">"#,
+            crate::render::make_permalink(&base_url, route.as_str())
+        )
+    };
+
+    let mut links: Vec<String> = versions.iter().map(|v| link(&v.lang, &v.route)).collect();
+    if let Some(default) = versions
+        .iter()
+        .find(|v| Some(&v.lang) == default_code.as_ref())
+    {
+        links.push(link("x-default", &default.route));
+    }
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn languages() -> Vec<Language> {
+        vec![
+            Language {
+                code: "en".into(),
+                name: "English".into(),
+                prefix: String::new(),
+            },
+            Language {
+                code: "fr".into(),
+                name: "Français".into(),
+                prefix: "/fr".into(),
+            },
+        ]
+    }
+
+    #[test]
+    fn split_source_path_reads_language_suffix() {
+        let langs = languages();
+        let split = |path| {
+            split_source_path(path, &langs).map(|(lang, original)| (lang.code.clone(), original))
+        };
+        assert_eq!(
+            split("guide/intro.fr.md"),
+            Some(("fr".into(), "guide/intro.md".into()))
+        );
+        assert_eq!(
+            split("guide/_index.fr.md"),
+            Some(("fr".into(), "guide/_index.md".into()))
+        );
+        assert_eq!(
+            split("guide/intro.md"),
+            Some(("en".into(), "guide/intro.md".into()))
+        );
+        // Unknown codes are part of the file name.
+        assert_eq!(
+            split("release.v2.md"),
+            Some(("en".into(), "release.v2.md".into()))
+        );
+        assert_eq!(split_source_path("guide/intro.fr.md", &[]), None);
+    }
+
+    #[test]
+    fn routes_move_under_language_prefix_and_back() {
+        let langs = languages();
+        assert_eq!(localize(&Route::root(), "/fr").as_str(), "/fr");
+        assert_eq!(
+            localize(&Route::new("/guide".into()), "/fr").as_str(),
+            "/fr/guide"
+        );
+        assert_eq!(
+            localize(&Route::new("/guide".into()), "").as_str(),
+            "/guide"
+        );
+
+        assert_eq!(language_of_route("/fr/guide", &langs).unwrap().code, "fr");
+        assert_eq!(language_of_route("/fr", &langs).unwrap().code, "fr");
+        assert_eq!(language_of_route("/french", &langs).unwrap().code, "en");
+
+        assert_eq!(strip_language_prefix("/fr/guide", &langs), "/guide");
+        assert_eq!(strip_language_prefix("/fr", &langs), "/");
+        assert_eq!(strip_language_prefix("/guide", &langs), "/guide");
+    }
+
+    #[test]
+    fn localized_source_map_prefers_translations() {
+        let langs = languages();
+        let map: HashMap<String, String> = [
+            ("intro.md", "/intro"),
+            ("intro.fr.md", "/fr/intro"),
+            ("only-english.md", "/only-english"),
+        ]
+        .into_iter()
+        .map(|(s, r)| (s.to_string(), r.to_string()))
+        .collect();
+
+        let fr = localized_source_map(&map, "fr", &langs);
+        assert_eq!(fr["intro.md"], "/fr/intro");
+        assert_eq!(fr["only-english.md"], "/only-english");
+        assert_eq!(fr["intro.fr.md"], "/fr/intro");
+
+        let en = localized_source_map(&map, "en", &langs);
+        assert_eq!(en["intro.md"], "/intro");
+    }
+}
@@ -34,7 +34,7 @@ impl PartialEq for Embedding {
 impl Eq for Embedding {}
 
 impl Embedding {
-    fn dot(&self, other: &[f32]) -> f32 {
+    pub(crate) fn dot(&self, other: &[f32]) -> f32 {
         std::iter::zip(&self.values, other)
             .map(|(a, b)| a * b)
             .sum()
@@ -173,31 +173,40 @@ pub async fn search<DB: Db>(db: &DB, query: &str, k: usize) -> KnowledgeResponse
 pub async fn related<DB: Db>(db: &DB, route: &str, k: usize) -> KnowledgeResponse {
     let query = format!("related:{route}");
     let chunks = all_chunks(db).await.unwrap_or_default();
-    let dim = chunks
-        .iter()
-        .find(|c| c.route == route)
-        .map_or(0, |c| c.embedding.values.len());
-    if dim == 0 {
+    let Some(centroid) = centroid(chunks.iter().filter(|c| c.route == route)) else {
         return KnowledgeResponse {
             query,
             hits: Vec::new(),
         };
+    };
+    KnowledgeResponse {
+        query,
+        hits: top_k(&chunks, &centroid, k, Some(route)),
     }
-    let mut centroid = vec![0f32; dim];
+}
+
+/// Mean of the chunks' vectors; `None` when there are none.
+pub(crate) fn centroid<'a>(
+    chunks: impl IntoIterator<Item = &'a EmbeddedChunk>,
+) -> Option<Vec<f32>> {
+    let mut centroid: Vec<f32> = Vec::new();
     let mut n = 0usize;
-    for c in chunks.iter().filter(|c| c.route == route) {
+    for c in chunks {
+        if centroid.is_empty() {
+            centroid = vec![0f32; c.embedding.values.len()];
+        }
         for (acc, v) in std::iter::zip(&mut centroid, &c.embedding.values) {
             *acc += v;
         }
         n += 1;
     }
+    if n == 0 || centroid.is_empty() {
+        return None;
+    }
     for acc in &mut centroid {
         *acc /= n as f32;
     }
-    KnowledgeResponse {
-        query,
-        hits: top_k(&chunks, &centroid, k, Some(route)),
-    }
+    Some(centroid)
 }
 
 /// A page's connection graph: explicit links out, pages linking in (from the
@@ -20,29 +20,40 @@ pub mod config;
 pub mod content_service;
 pub mod coverage;
 pub mod data;
+pub mod dates;
 pub mod db;
 pub mod dictionary;
 pub mod edit_session;
 pub mod edit_shell;
 pub mod error_pages;
+pub mod feeds;
 pub mod file_watcher;
 pub mod frontmatter_schema;
 pub mod host;
+pub mod i18n;
 pub mod image;
 pub mod includes;
 pub mod init;
 pub mod knowledge;
 pub mod link_checker;
 pub mod logging;
+pub mod pagination;
+pub mod permalinks;
 pub mod queries;
+pub mod redirects;
+pub mod related;
 pub mod render;
 pub mod revision;
+pub mod schedule;
 pub mod search;
+pub mod series;
 pub mod serve;
 pub mod shortcode;
+pub mod sitemap;
 pub mod spawn;
 pub mod status;
 pub mod svg;
+pub mod taxonomy;
 pub mod template_host;
 pub mod template_paths;
 pub mod theme_resolver;
@@ -0,0 +1,190 @@
+//! Section pagination: `paginate_by = N` in a section's frontmatter splits its
+//! page listing across `/blog`, `/blog/page/2`, `/blog/page/3`, …
+//!
+//! The first pager is the section route itself; the rest are collected into
+//! [`SiteTree::pagers`](crate::db::SiteTree) by `build_tree` so every consumer
+//! of the route set (build, serve, link checking, search) sees them.
+
+use std::collections::BTreeMap;
+
+use crate::db::{Page, Pager, Section};
+use crate::types::Route;
+
+/// Path segment pagers live under (`/blog/page/2`).
+const PAGER_SEGMENT: &str = "page";
+
+/// A section's pages in listing order: by weight, then by route.
+pub fn listed_pages<'a>(pages: &'a BTreeMap<Route, Page>, section: &Route) -> Vec<&'a Page> {
+    let mut listed: Vec<&Page> = pages
+        .values()
+        .filter(|p| p.section_route == *section)
+        .collect();
+    listed.sort_by_key(|p| p.weight);
+    listed
+}
+
+/// Route of pager `index` (1-based) for a section. Pager 1 is the section.
+pub fn pager_route(section: &Route, index: u32) -> Route {
+    if index <= 1 {
+        return section.clone();
+    }
+    let base = section.as_str().trim_end_matches('/');
+    Route::new(format!("{base}/{PAGER_SEGMENT}/{index}"))
+}
+
+/// Number of pagers a section listing `page_count` pages needs (at least one).
+pub fn pager_count(page_count: usize, paginate_by: u32) -> u32 {
+    let per_page = paginate_by.max(1) as usize;
+    page_count.div_ceil(per_page).max(1) as u32
+}
+
+/// Pagers beyond the first for every paginated section. Content at a pager
+/// route takes precedence, so those are left out.
+pub fn collect(
+    sections: &BTreeMap<Route, Section>,
+    pages: &BTreeMap<Route, Page>,
+) -> BTreeMap<Route, Pager> {
+    let mut pagers = BTreeMap::new();
+    for section in sections.values() {
+        let Some(paginate_by) = section.paginate_by.filter(|n| *n > 0) else {
+            continue;
+        };
+        let count = pager_count(listed_pages(pages, &section.route).len(), paginate_by);
+        for index in 2..=count {
+            let route = pager_route(&section.route, index);
+            if sections.contains_key(&route) || pages.contains_key(&route) {
+                continue;
+            }
+            pagers.insert(
+                route.clone(),
+                Pager {
+                    section: section.route.clone(),
+                    route,
+                    index,
+                },
+            );
+        }
+    }
+    pagers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::SourceMap;
+    use crate::sitemap::SitemapSettings;
+    use crate::types::{HtmlBody, Title};
+    use facet_value::Value;
+
+    fn section(route: &str, paginate_by: Option<u32>) -> Section {
+        Section {
+            route: Route::new(route.to_string()),
+            title: Title::new(route.to_string()),
+            description: None,
+            weight: 0,
+            body_html: HtmlBody::from_static(""),
+            headings: Vec::new(),
+            reqs: Vec::new(),
+            source_map: SourceMap::default(),
+            head_injections: Vec::new(),
+            last_updated: 0,
+            date: None,
+            updated: None,
+            draft: false,
+            sitemap: SitemapSettings::default(),
+            paginate_by,
+            feeds: Vec::new(),
+            lang: None,
+            extra: Value::default(),
+            template: None,
+        }
+    }
+
+    fn page(route: &str, section: &str, weight: i32) -> Page {
+        Page {
+            route: Route::new(route.to_string()),
+            title: Title::new(route.to_string()),
+            weight,
+            body_html: HtmlBody::from_static(""),
+            section_route: Route::new(section.to_string()),
+            headings: Vec::new(),
+            rules: Vec::new(),
+            source_map: SourceMap::default(),
+            head_injections: Vec::new(),
+            last_updated: 0,
+            date: None,
+            updated: None,
+            draft: false,
+            sitemap: SitemapSettings::default(),
+            series: None,
+            lang: None,
+            taxonomies: BTreeMap::new(),
+            extra: Value::default(),
+            template: None,
+        }
+    }
+
+    fn by_route<T>(items: impl IntoIterator<Item = (Route, T)>) -> BTreeMap<Route, T> {
+        items.into_iter().collect()
+    }
+
+    #[test]
+    fn pager_routes_hang_off_the_section() {
+        let blog = Route::new("/blog".to_string());
+        assert_eq!(pager_route(&blog, 1), blog);
+        assert_eq!(pager_route(&blog, 3).as_str(), "/blog/page/3");
+        assert_eq!(pager_route(&Route::root(), 2).as_str(), "/page/2");
+    }
+
+    #[test]
+    fn pager_count_rounds_up_and_never_hits_zero() {
+        assert_eq!(pager_count(0, 10), 1);
+        assert_eq!(pager_count(10, 10), 1);
+        assert_eq!(pager_count(11, 10), 2);
+        assert_eq!(pager_count(5, 0), 5);
+    }
+
+    #[test]
+    fn collect_emits_extra_pagers_for_paginated_sections_only() {
+        let sections = by_route(
+            [section("/", None), section("/blog", Some(2))]
+                .into_iter()
+                .map(|s| (s.route.clone(), s)),
+        );
+        let pages = by_route(
+            [
+                page("/blog/a", "/blog", 0),
+                page("/blog/b", "/blog", 0),
+                page("/blog/c", "/blog", 0),
+                page("/blog/d", "/blog", 0),
+                page("/blog/e", "/blog", 0),
+                page("/about", "/", 0),
+            ]
+            .into_iter()
+            .map(|p| (p.route.clone(), p)),
+        );
+
+        let pagers = collect(&sections, &pages);
+        let routes: Vec<&str> = pagers.keys().map(|r| r.as_str()).collect();
+        assert_eq!(routes, ["/blog/page/2", "/blog/page/3"]);
+        assert_eq!(pagers[&Route::new("/blog/page/3".to_string())].index, 3);
+    }
+
+    #[test]
+    fn listed_pages_sort_by_weight() {
+        let pages = by_route(
+            [
+                page("/blog/a", "/blog", 2),
+                page("/blog/b", "/blog", 1),
+                page("/other", "/", 0),
+            ]
+            .into_iter()
+            .map(|p| (p.route.clone(), p)),
+        );
+        let listed: Vec<&str> = listed_pages(&pages, &Route::new("/blog".to_string()))
+            .iter()
+            .map(|p| p.route.as_str())
+            .collect();
+        assert_eq!(listed, ["/blog/b", "/blog/a"]);
+    }
+}
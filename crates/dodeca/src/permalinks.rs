@@ -0,0 +1,206 @@
+//! Permalink patterns.
+//!
+//! `site.permalinks` lays pages out by pattern instead of by file path. A key
+//! starting with `/` names a section and routes every page below it; any other
+//! key names a page type (frontmatter `extra.type`), which wins over the
+//! section. With `/blog "/blog/{year}/{month}/{slug}/"`, `blog/hello.md` dated
+//! 2024-05-01 is served at `/blog/2024/05/hello`. Section indexes keep their
+//! directory routes.
+//!
+//! The expanded route is the page's route: `@/` links, wiki links and the
+//! editor all resolve through it, and two pages expanding to the same route
+//! are a duplicate-route error like any other.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Datelike};
+use eyre::{Result, eyre};
+use picante::PicanteResult;
+
+use crate::cells::MarkdownParseError;
+use crate::db::{ConfigRegistry, Db};
+use crate::types::Route;
+
+/// Placeholders a pattern may use: the page's `date` parts and its slug
+/// (frontmatter `slug`, else its file name).
+pub const PLACEHOLDERS: &[&str] = &["year", "month", "day", "slug"];
+
+/// The configured patterns. Tracked on the config input so only a
+/// `site.permalinks` edit re-routes pages.
+#[picante::tracked]
+pub async fn site_permalinks<DB: Db>(db: &DB) -> PicanteResult<BTreeMap<String, String>> {
+    Ok(ConfigRegistry::config(db)?
+        .map(|c| c.permalinks.clone())
+        .unwrap_or_default())
+}
+
+/// Check `site.permalinks` and normalize section keys to routes (`/blog/` →
+/// `/blog`).
+pub fn resolve(defs: HashMap<String, String>) -> Result<BTreeMap<String, String>> {
+    let mut resolved = BTreeMap::new();
+    for (key, pattern) in defs {
+        if !pattern.starts_with('/') {
+            return Err(eyre!(
+                "permalink pattern `{pattern}` for `{key}` must start with `/`"
+            ));
+        }
+        for name in placeholders(&pattern).map_err(|e| eyre!("permalink `{key}`: {e}"))? {
+            if !PLACEHOLDERS.contains(&name) {
+                return Err(eyre!(
+                    "permalink pattern `{pattern}` uses unknown placeholder `{{{name}}}`; expected one of {}",
+                    PLACEHOLDERS.join(", ")
+                ));
+            }
+        }
+        let key = if key.starts_with('/') {
+            normalize(&key)
+        } else {
+            key
+        };
+        resolved.insert(key, pattern);
+    }
+    Ok(resolved)
+}
+
+/// The pattern that routes a page: its type's, else the nearest enclosing
+/// section's. `route` is the page's file-derived route.
+pub fn pattern_for<'a>(
+    permalinks: &'a BTreeMap<String, String>,
+    route: &Route,
+    page_type: Option<&str>,
+) -> Option<&'a str> {
+    if let Some(pattern) = page_type.and_then(|t| permalinks.get(t)) {
+        return Some(pattern);
+    }
+    let mut section = route.parent();
+    while let Some(route) = section {
+        if let Some(pattern) = permalinks.get(route.as_str()) {
+            return Some(pattern);
+        }
+        section = route.parent();
+    }
+    None
+}
+
+/// Fill in `pattern` for one page.
+pub fn expand(pattern: &str, slug: &str, date: Option<i64>) -> Result<Route, MarkdownParseError> {
+    let date = date.and_then(|ts| DateTime::from_timestamp(ts, 0));
+    let mut out = String::new();
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        let name = &rest[start + 1..start + len];
+        let value = match name {
+            "slug" => slug.to_string(),
+            "year" | "month" | "day" => {
+                let Some(date) = date else {
+                    return Err(MarkdownParseError {
+                        message: format!(
+                            "permalink pattern '{pattern}' uses {{{name}}} but the page has no `date`"
+                        ),
+                    });
+                };
+                match name {
+                    "year" => format!("{:04}", date.year()),
+                    "month" => format!("{:02}", date.month()),
+                    _ => format!("{:02}", date.day()),
+                }
+            }
+            _ => {
+                return Err(MarkdownParseError {
+                    message: format!("permalink pattern '{pattern}' uses unknown {{{name}}}"),
+                });
+            }
+        };
+        out.push_str(&value);
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    Ok(Route::new(normalize(&out)))
+}
+
+/// Placeholder names in `pattern`, in order.
+fn placeholders(pattern: &str) -> Result<Vec<&str>, String> {
+    let mut names = Vec::new();
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            return Err(format!("unclosed `{{` in `{pattern}`"));
+        };
+        names.push(&rest[start + 1..start + len]);
+        rest = &rest[start + len + 1..];
+    }
+    Ok(names)
+}
+
+/// Route form of a path: leading slash, no trailing or doubled slashes.
+fn normalize(path: &str) -> String {
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    format!("/{}", segments.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn permalinks(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        resolve(
+            entries
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn expands_date_parts_and_slug() {
+        // 2024-05-01T12:00:00Z
+        let date = Some(1_714_564_800);
+        let route = expand("/blog/{year}/{month}/{day}/{slug}/", "hello", date).unwrap();
+        assert_eq!(route.as_str(), "/blog/2024/05/01/hello");
+        assert_eq!(expand("/{slug}", "about", None).unwrap().as_str(), "/about");
+    }
+
+    #[test]
+    fn date_placeholders_need_a_date() {
+        let err = expand("/blog/{year}/{slug}/", "hello", None).unwrap_err();
+        assert!(err.message.contains("no `date`"), "{}", err.message);
+    }
+
+    #[test]
+    fn page_type_wins_over_nearest_section() {
+        let permalinks = permalinks(&[
+            ("/blog/", "/posts/{slug}/"),
+            ("/blog/archive", "/old/{slug}/"),
+            ("recipe", "/recipes/{slug}/"),
+        ]);
+        let route = |r: &str| Route::new(r.to_string());
+
+        assert_eq!(
+            pattern_for(&permalinks, &route("/blog/hello"), None),
+            Some("/posts/{slug}/")
+        );
+        assert_eq!(
+            pattern_for(&permalinks, &route("/blog/archive/2019/hello"), None),
+            Some("/old/{slug}/")
+        );
+        assert_eq!(
+            pattern_for(&permalinks, &route("/blog/soup"), Some("recipe")),
+            Some("/recipes/{slug}/")
+        );
+        assert_eq!(pattern_for(&permalinks, &route("/guide/intro"), None), None);
+    }
+
+    #[test]
+    fn rejects_malformed_patterns() {
+        let one = |k: &str, v: &str| resolve(HashMap::from([(k.to_string(), v.to_string())]));
+        assert!(one("/blog", "blog/{slug}").is_err());
+        assert!(one("/blog", "/blog/{title}").is_err());
+        assert!(one("/blog", "/blog/{slug").is_err());
+        assert!(one("/blog", "/blog/{year}/{slug}/").is_ok());
+    }
+}
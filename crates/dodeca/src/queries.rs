@@ -1,19 +1,21 @@
 use crate::db::{
     AllRenderedHtml, CharSet, CodeExecutionMetadata, CodeExecutionResult, CssOutput, DataRegistry,
     Db, DependencySourceInfo, ExternalLinkStatus, Heading, ImageVariant, MarkdownRenderSettings,
-    OutputFile, Page, ParsedData, ProcessedImages, RenderedHtml, RenderedMarkdown, ReqDefinition,
-    ResolvedDependencyInfo, SassFile, SassRegistry, Section, SiteOutput, SiteTree, SourceFile,
-    SourceKind, SourceMap, SourceMapEntry, SourceRegistry, StaticFile, StaticFileOutput,
-    StaticRegistry, TemplateFile, TemplateRegistry,
+    OutputFile, Page, ParsedData, ProcessedImages, PublishSettings, RenderedHtml, RenderedMarkdown,
+    ReqDefinition, ResolvedDependencyInfo, SassFile, SassRegistry, Section, SiteOutput, SiteTree,
+    SourceFile, SourceKind, SourceMap, SourceMapEntry, SourceRegistry, StaticFile,
+    StaticFileOutput, StaticRegistry, TemplateFile, TemplateRegistry,
 };
 use picante::PicanteResult;
 
 use crate::cells::{MarkdownParseError, parse_and_render_markdown};
 use crate::image::{self, InputFormat, OutputFormat, add_width_suffix};
-use crate::types::{HtmlBody, Route, SassContent, StaticPath, TemplateContent, Title};
+use crate::sitemap::SitemapSettings;
+use crate::types::{HtmlBody, Route, SassContent, SourcePath, StaticPath, TemplateContent, Title};
 use crate::url_rewrite::{rewrite_string_literals_in_js, rewrite_urls_in_css};
 use facet::Facet;
 use facet_value::{DestructuredRef, Value};
+use std::collections::hash_map::Entry;
 use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Load a template file's content - tracked for dependency tracking
@@ -330,6 +332,37 @@ pub struct Frontmatter {
     pub template: Option<String>,
     pub asset: Option<String>,
     pub data: Option<String>,
+    /// Publication date (`YYYY-MM-DD` or RFC 3339)
+    pub date: Option<crate::dates::FrontmatterDate>,
+    /// Last-updated date, same formats as `date`
+    pub updated: Option<crate::dates::FrontmatterDate>,
+    /// Drafts are previewed by `ddc serve` and left out of `ddc build`
+    #[facet(default)]
+    pub draft: bool,
+    /// Keeps the page unpublished until this moment, same formats as `date`
+    pub publish_at: Option<crate::dates::FrontmatterDate>,
+    /// Overrides the last segment of the page's route
+    pub slug: Option<String>,
+    /// Former routes of the page, redirected to its current one
+    #[facet(default)]
+    pub aliases: Vec<String>,
+    /// Split a section's page listing into pages of this many entries
+    pub paginate_by: Option<u32>,
+    /// Feed formats a section publishes: `atom`, `rss`, `json`
+    #[facet(default)]
+    pub feeds: Vec<String>,
+    /// `false` leaves the page out of `sitemap.xml`
+    pub sitemap: Option<bool>,
+    /// Sitemap `<priority>`, from 0.0 to 1.0
+    pub sitemap_priority: Option<f64>,
+    /// `false` keeps the page out of related-page suggestions
+    pub related: Option<bool>,
+    /// Terms per taxonomy from the `[taxonomies]` table
+    #[facet(default)]
+    pub taxonomies: Value,
+    /// Series membership from the `[series]` table
+    #[facet(default)]
+    pub series: Value,
     /// Custom fields from the `[extra]` table in frontmatter
     #[facet(default)]
     pub extra: Value,
@@ -389,6 +422,41 @@ pub async fn parse_file<DB: Db>(db: &DB, source: SourceFile) -> PicanteResult<Pa
 
     // Convert frontmatter from cell type
     let extra: Value = frontmatter.extra.clone();
+    let date = match parse_date_field("date", frontmatter.date.as_deref()) {
+        Ok(date) => date,
+        Err(e) => return Ok(Err(e)),
+    };
+    let updated = match parse_date_field("updated", frontmatter.updated.as_deref()) {
+        Ok(updated) => updated,
+        Err(e) => return Ok(Err(e)),
+    };
+    let publish_at = match parse_date_field("publish_at", frontmatter.publish_at.as_deref()) {
+        Ok(publish_at) => publish_at,
+        Err(e) => return Ok(Err(e)),
+    };
+    let taxonomies = match crate::taxonomy::frontmatter_taxonomies(&frontmatter.taxonomies) {
+        Ok(taxonomies) => taxonomies,
+        Err(e) => return Ok(Err(e)),
+    };
+    let feeds = match crate::feeds::parse_feed_formats(&frontmatter.feeds) {
+        Ok(feeds) => feeds,
+        Err(e) => return Ok(Err(e)),
+    };
+    let aliases = match crate::redirects::parse_aliases(&frontmatter.aliases) {
+        Ok(aliases) => aliases,
+        Err(e) => return Ok(Err(e)),
+    };
+    let series = match crate::series::frontmatter_series(&frontmatter.series) {
+        Ok(series) => series,
+        Err(e) => return Ok(Err(e)),
+    };
+    let sitemap = match crate::sitemap::parse_sitemap_settings(
+        frontmatter.sitemap,
+        frontmatter.sitemap_priority,
+    ) {
+        Ok(sitemap) => sitemap,
+        Err(e) => return Ok(Err(e)),
+    };
 
     // Convert headings from cell type to internal type
     let headings: Vec<Heading> = headings_raw
@@ -415,14 +483,52 @@ pub async fn parse_file<DB: Db>(db: &DB, source: SourceFile) -> PicanteResult<Pa
 
     let body_html = HtmlBody::new(html_output);
 
+    // A translation (`intro.fr.md`) is laid out like its original
+    // (`intro.md`), then moved under its language's route prefix.
+    let languages = crate::i18n::site_languages(db).await?;
+    let (lang, original) = match crate::i18n::split_source_path(path.as_str(), &languages) {
+        Some((lang, original)) => (Some(lang), SourcePath::new(original)),
+        None => (None, (*path).clone()),
+    };
+
     // Determine if this is a section (_index.md)
-    let is_section = path.is_section_index();
+    let is_section = original.is_section_index();
 
-    // Compute URL route
-    let route = path.to_route();
+    // Compute URL route: a permalink pattern for the page's type or section
+    // lays it out from frontmatter; otherwise a frontmatter slug replaces the
+    // last segment of its file-derived route
+    if let Some(slug) = frontmatter.slug.as_deref()
+        && let Err(e) = validate_slug(slug, is_section)
+    {
+        return Ok(Err(e));
+    }
+    let file_route = original.to_route();
+    let permalinks = crate::permalinks::site_permalinks(db).await?;
+    let pattern = if is_section {
+        None
+    } else {
+        let page_type = crate::frontmatter_schema::frontmatter_type(&extra);
+        crate::permalinks::pattern_for(&permalinks, &file_route, page_type)
+    };
+    let route = match (pattern, frontmatter.slug.as_deref()) {
+        (Some(pattern), slug) => {
+            let file_slug = file_route.as_str().rsplit('/').next().unwrap_or_default();
+            let slug = slug.map_or(file_slug, |s| s.trim_matches('/'));
+            match crate::permalinks::expand(pattern, slug, date) {
+                Ok(route) => route,
+                Err(e) => return Ok(Err(e)),
+            }
+        }
+        (None, Some(slug)) => file_route.with_slug(slug),
+        (None, None) => file_route,
+    };
+    let route = match lang {
+        Some(lang) => crate::i18n::localize(&route, &lang.prefix),
+        None => route,
+    };
 
     let title = if frontmatter.title.trim().is_empty() {
-        default_title_from_source_path(path.as_str())
+        default_title_from_source_path(original.as_str())
     } else {
         frontmatter.title
     };
@@ -440,17 +546,63 @@ pub async fn parse_file<DB: Db>(db: &DB, source: SourceFile) -> PicanteResult<Pa
         source_map,
         head_injections,
         last_updated: last_modified,
+        date,
+        updated,
+        draft: frontmatter.draft,
+        publish_at,
+        paginate_by: frontmatter.paginate_by,
+        feeds,
+        sitemap,
+        related: frontmatter.related.unwrap_or(true),
+        aliases,
+        series,
+        taxonomies,
+        lang: lang.map(|l| l.code.clone()),
         extra,
         template: frontmatter.template,
     }))
 }
 
+/// A slug renames one page; it cannot rename a section (its pages would keep
+/// the directory's route) or add path segments.
+fn validate_slug(slug: &str, is_section: bool) -> Result<(), MarkdownParseError> {
+    if is_section {
+        return Err(MarkdownParseError {
+            message: format!(
+                "frontmatter slug '{slug}' is not supported on _index.md: rename the directory instead"
+            ),
+        });
+    }
+    if slug.trim_matches('/').contains('/') {
+        return Err(MarkdownParseError {
+            message: format!("frontmatter slug '{slug}' must be a single path segment"),
+        });
+    }
+    Ok(())
+}
+
+fn parse_date_field(field: &str, value: Option<&str>) -> Result<Option<i64>, MarkdownParseError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    crate::dates::parse_frontmatter_date(value)
+        .map(Some)
+        .ok_or_else(|| MarkdownParseError {
+            message: format!(
+                "invalid frontmatter {field} '{value}': expected YYYY-MM-DD or an RFC 3339 datetime"
+            ),
+        })
+}
+
 pub fn default_title_from_source_path(path: &str) -> String {
     let path = path.strip_suffix(".md").unwrap_or(path);
     let slug = if path == "_index" {
         "home"
-    } else if let Some(section_path) = path.strip_suffix("/_index") {
-        section_path.rsplit('/').next().unwrap_or("home")
+    } else if let Some(dir) = path
+        .strip_suffix("/_index")
+        .or_else(|| path.strip_suffix("/index"))
+    {
+        dir.rsplit('/').next().unwrap_or("home")
     } else {
         path.rsplit('/').next().unwrap_or("home")
     };
@@ -458,7 +610,7 @@ pub fn default_title_from_source_path(path: &str) -> String {
     title_case_slug(slug)
 }
 
-fn title_case_slug(slug: &str) -> String {
+pub(crate) fn title_case_slug(slug: &str) -> String {
     let mut title = String::new();
     let mut capitalize_next = true;
 
@@ -678,6 +830,9 @@ impl From<WikiLinkBuildError> for SiteError {
 /// Result of building the site tree
 pub type BuildTreeResult = Result<SiteTree, Vec<SourceParseError>>;
 
+/// Injected into the `<head>` of draft pages so previews are unmistakable.
+const DRAFT_MARKER: &str = r#"<style id="dodeca-draft-marker">body::before{content:"Draft";position:fixed;top:0.75rem;right:0.75rem;z-index:2147483647;padding:0.2rem 0.6rem;border-radius:0.25rem;background:#b45309;color:#fff;font:600 0.75rem/1.4 system-ui,sans-serif;letter-spacing:0.05em;text-transform:uppercase;pointer-events:none}</style>"#;
+
 /// Build the site tree from all source files
 /// This tracked query depends on all parse_file results
 #[picante::tracked]
@@ -718,6 +873,25 @@ pub async fn build_tree<DB: Db>(db: &DB) -> PicanteResult<BuildTreeResult> {
             .collect()));
     }
 
+    // Drafts only exist in the tree when previewing; production builds drop
+    // them before anything (listings, search, links) can see them.
+    if PublishSettings::include_drafts(db)?.unwrap_or(false) {
+        for data in parsed.iter_mut().filter(|d| d.draft) {
+            data.head_injections.push(DRAFT_MARKER.to_string());
+        }
+    } else {
+        parsed.retain(|d| !d.draft);
+    }
+    // Pages scheduled for later are hidden in every mode until the clock
+    // reaches them.
+    let embargoed = crate::schedule::embargoed_sources(db).await?;
+    parsed.retain(|d| !embargoed.iter().any(|e| e.source_path == d.source_path));
+
+    let route_errors = duplicate_route_errors(&parsed);
+    if !route_errors.is_empty() {
+        return Ok(Err(route_errors));
+    }
+
     // First pass: create all sections
     for data in parsed.iter().filter(|d| d.is_section) {
         sections.insert(
@@ -733,6 +907,13 @@ pub async fn build_tree<DB: Db>(db: &DB) -> PicanteResult<BuildTreeResult> {
                 source_map: data.source_map.clone(),
                 head_injections: data.head_injections.clone(),
                 last_updated: data.last_updated,
+                date: data.date,
+                updated: data.updated,
+                draft: data.draft,
+                paginate_by: data.paginate_by,
+                feeds: data.feeds.clone(),
+                sitemap: data.sitemap.clone(),
+                lang: data.lang.clone(),
                 extra: data.extra.clone(),
                 template: data.template.clone(),
             },
@@ -740,21 +921,34 @@ pub async fn build_tree<DB: Db>(db: &DB) -> PicanteResult<BuildTreeResult> {
     }
 
     // Ensure root section exists
-    sections.entry(Route::root()).or_insert_with(|| Section {
-        route: Route::root(),
-        title: Title::from_static("Home"),
-        description: None,
-        weight: 0,
-        body_html: HtmlBody::from_static(""),
-        headings: Vec::new(),
-        reqs: Vec::new(),
-        source_map: SourceMap::default(),
-        head_injections: Vec::new(),
-        last_updated: 0,
-        extra: Value::default(),
-        template: None,
+    let languages = crate::i18n::site_languages(db).await?;
+    sections.entry(Route::root()).or_insert_with(|| {
+        generated_section(
+            Route::root(),
+            Title::from_static("Home"),
+            languages.first().map(|l| l.code.clone()),
+        )
     });
 
+    // ...and a home for every other language that has content
+    for lang in languages.iter().skip(1) {
+        if parsed.iter().any(|d| d.lang.as_ref() == Some(&lang.code)) {
+            let home = crate::i18n::localize(&Route::root(), &lang.prefix);
+            sections.entry(home.clone()).or_insert_with(|| {
+                generated_section(home, Title::new(lang.name.clone()), Some(lang.code.clone()))
+            });
+        }
+    }
+
+    // Read through the input (not `global_config()`) so a `site.taxonomies`
+    // or `site.redirects` edit invalidates the tree in every mode, scoped task
+    // or not.
+    let config = crate::db::ConfigRegistry::config(db)?;
+    let taxonomy_defs = config
+        .as_ref()
+        .map(|cfg| cfg.taxonomies.clone())
+        .unwrap_or_default();
+
     // Second pass: create pages and assign to sections
     for data in parsed.iter().filter(|d| !d.is_section) {
         let section_route = find_parent_section(&data.route, &sections);
@@ -771,21 +965,94 @@ pub async fn build_tree<DB: Db>(db: &DB) -> PicanteResult<BuildTreeResult> {
                 source_map: data.source_map.clone(),
                 head_injections: data.head_injections.clone(),
                 last_updated: data.last_updated,
+                date: data.date,
+                updated: data.updated,
+                draft: data.draft,
+                sitemap: data.sitemap.clone(),
+                series: data.series.clone(),
+                lang: data.lang.clone(),
+                taxonomies: crate::taxonomy::page_terms(data, &taxonomy_defs),
                 extra: data.extra.clone(),
                 template: data.template.clone(),
             },
         );
     }
 
+    let pagers = crate::pagination::collect(&sections, &pages);
+    let taxonomies = crate::taxonomy::collect(&pages, &taxonomy_defs);
+    let series = crate::series::collect(&pages);
+    let translations = crate::i18n::collect(&parsed, &languages);
+
     // Wiki auto-linking: rewrite wiki page/section bodies to link bare mentions
     // of other wiki pages. Runs here because it needs every title; the markdown
     // source is never modified (only the rendered `body_html`).
-    let mut tree = SiteTree { sections, pages };
+    let mut tree = SiteTree {
+        sections,
+        pages,
+        pagers,
+        taxonomies,
+        series,
+        translations,
+        redirects: BTreeMap::new(),
+    };
+    let site_redirects = config.map(|cfg| cfg.redirects.clone()).unwrap_or_default();
+    tree.redirects = crate::redirects::collect(&parsed, &site_redirects, &tree);
     crate::wiki::apply_auto_links(&mut tree);
 
     Ok(Ok(tree))
 }
 
+/// Sources that claim a route already taken by another source (e.g. two posts
+/// with the same `slug`). Without this check the later one would silently
+/// replace the earlier one in the tree.
+fn duplicate_route_errors(parsed: &[ParsedData]) -> Vec<SourceParseError> {
+    let mut owners: HashMap<&Route, &SourcePath> = HashMap::new();
+    let mut errors = Vec::new();
+    for data in parsed {
+        match owners.entry(&data.route) {
+            Entry::Vacant(entry) => {
+                entry.insert(&data.source_path);
+            }
+            Entry::Occupied(entry) => errors.push(SourceParseError {
+                path: data.source_path.to_string(),
+                error: MarkdownParseError {
+                    message: format!(
+                        "route {} is also claimed by {}; give one of them a different slug",
+                        data.route,
+                        entry.get()
+                    ),
+                },
+            }),
+        }
+    }
+    errors
+}
+
+/// An empty section standing in for a missing `_index.md`.
+fn generated_section(route: Route, title: Title, lang: Option<String>) -> Section {
+    Section {
+        route,
+        title,
+        description: None,
+        weight: 0,
+        body_html: HtmlBody::from_static(""),
+        headings: Vec::new(),
+        reqs: Vec::new(),
+        source_map: SourceMap::default(),
+        head_injections: Vec::new(),
+        last_updated: 0,
+        date: None,
+        updated: None,
+        draft: false,
+        paginate_by: None,
+        feeds: Vec::new(),
+        sitemap: SitemapSettings::default(),
+        lang,
+        extra: Value::default(),
+        template: None,
+    }
+}
+
 /// Build a mapping from source paths to routes.
 ///
 /// This is used to resolve `@/` links in markdown. When a page contains `@/guide/intro.md`,
@@ -797,11 +1064,18 @@ pub async fn build_tree<DB: Db>(db: &DB) -> PicanteResult<BuildTreeResult> {
 #[picante::tracked]
 pub async fn source_to_route_map<DB: Db>(db: &DB) -> PicanteResult<HashMap<String, String>> {
     let sources = SourceRegistry::sources(db)?.unwrap_or_default();
+    let include_drafts = PublishSettings::include_drafts(db)?.unwrap_or(false);
+    let embargoed = crate::schedule::embargoed_sources(db).await?;
     let mut map = HashMap::new();
 
     for source in sources.iter() {
-        // Calling parse_file creates a dependency on this source
-        if let Ok(data) = parse_file(db, *source).await? {
+        // Calling parse_file creates a dependency on this source. Drafts and
+        // embargoed pages left out of the tree must not resolve either, or
+        // `@/` links to them would point at routes that are never emitted.
+        if let Ok(data) = parse_file(db, *source).await?
+            && (include_drafts || !data.draft)
+            && !embargoed.iter().any(|e| e.source_path == data.source_path)
+        {
             // Map source path to route
             // e.g., "guide/intro.md" -> "/guide/intro/"
             map.insert(data.source_path.to_string(), data.route.to_string());
@@ -845,61 +1119,114 @@ impl ResolveMap {
 /// qualified (`[[spec/build/overview]]` / `[[build/overview]]`) and resolve via
 /// the mount-qualified `global` namespace. A single-source site has one local
 /// namespace at the root mount and behaves exactly as before.
+///
+/// Both namespaces are split by language: a translated page resolves among
+/// its own language's pages first, then among the default language's. On a
+/// monolingual site the language is always `""`.
 #[derive(Debug, Clone, Default)]
 struct WikiLinkIndex {
-    /// Mount segment (`""` = root) → that source's local namespace.
-    local: HashMap<String, ResolveMap>,
-    /// Mount-qualified namespace for explicit cross-source links.
-    global: ResolveMap,
+    /// (Language, source name) → that source's local namespace.
+    local: HashMap<(String, String), ResolveMap>,
+    /// Language → mount-qualified namespace for explicit cross-source links.
+    global: HashMap<String, ResolveMap>,
+    /// Configured languages, default first; empty when monolingual.
+    languages: Vec<crate::config::Language>,
 }
 
 impl WikiLinkIndex {
-    fn build(site_tree: &SiteTree) -> Self {
-        let mut local: HashMap<String, HashMap<String, Vec<String>>> = HashMap::new();
-        let mut global: HashMap<String, Vec<String>> = HashMap::new();
+    fn build(site_tree: &SiteTree, languages: Vec<crate::config::Language>) -> Self {
+        let mut local: HashMap<(String, String), HashMap<String, Vec<String>>> = HashMap::new();
+        let mut global: HashMap<String, HashMap<String, Vec<String>>> = HashMap::new();
 
-        for section in site_tree.sections.values() {
+        let entries = site_tree
+            .sections
+            .values()
+            .map(|s| (&s.lang, &s.title, &s.route))
+            .chain(
+                site_tree
+                    .pages
+                    .values()
+                    .map(|p| (&p.lang, &p.title, &p.route)),
+            );
+        for (lang, title, route) in entries {
+            let lang = lang.clone().unwrap_or_default();
             add_provenance_candidates(
                 &mut local,
-                &mut global,
-                section.title.as_str(),
-                &section.route,
+                global.entry(lang.clone()).or_default(),
+                &lang,
+                title.as_str(),
+                route,
+                &languages,
             );
         }
-        for page in site_tree.pages.values() {
-            add_provenance_candidates(&mut local, &mut global, page.title.as_str(), &page.route);
-        }
 
         WikiLinkIndex {
             local: local
                 .into_iter()
-                .map(|(name, c)| (name, ResolveMap::from_candidates(c)))
+                .map(|(key, c)| (key, ResolveMap::from_candidates(c)))
+                .collect(),
+            global: global
+                .into_iter()
+                .map(|(lang, c)| (lang, ResolveMap::from_candidates(c)))
                 .collect(),
-            global: ResolveMap::from_candidates(global),
+            languages,
         }
     }
 
+    /// The (local, global) namespaces a page in `source` (a route or source
+    /// path) resolves through, most preferred first: its own language's,
+    /// then the default language's.
+    fn namespaces(&self, source: &str) -> Vec<(Option<&ResolveMap>, Option<&ResolveMap>)> {
+        let name = source_of(source).map(|s| s.name).unwrap_or_default();
+        let own = crate::i18n::language_of(source, &self.languages)
+            .map(|l| l.code.clone())
+            .unwrap_or_default();
+        let default = self
+            .languages
+            .first()
+            .map(|l| l.code.clone())
+            .unwrap_or_default();
+        let mut chain = vec![own.clone()];
+        if default != own {
+            chain.push(default);
+        }
+        chain
+            .into_iter()
+            .map(|lang| {
+                (
+                    self.local.get(&(lang.clone(), name.clone())),
+                    self.global.get(&lang),
+                )
+            })
+            .collect()
+    }
+
     /// Resolve a wiki-link key for the page identified by `source_path`. Tries
     /// the page's own source namespace first (bare/local links), then the
-    /// name-qualified namespace (explicit cross-source links).
+    /// name-qualified namespace (explicit cross-source links), in the page's
+    /// language and then the default one.
     fn resolve(&self, key: &str, source_path: &str) -> Option<&String> {
-        let name = source_of(source_path).map(|s| s.name).unwrap_or_default();
-        self.local
-            .get(name.as_str())
-            .and_then(|m| m.resolved.get(key))
-            .or_else(|| self.global.resolved.get(key))
+        self.namespaces(source_path)
+            .into_iter()
+            .find_map(|(local, global)| {
+                local
+                    .and_then(|m| m.resolved.get(key))
+                    .or_else(|| global.and_then(|m| m.resolved.get(key)))
+            })
     }
 
     /// The effective flat resolved map for a page in `source` (a route or source
     /// path): the name-qualified globals as a base, with the page's local
-    /// namespace overriding (bare links resolve locally). Used by the HTML-level
-    /// wiki-link pass, which resolves against a single map per page.
+    /// namespace overriding (bare links resolve locally), and the page's own
+    /// language overriding the default. Used by the HTML-level wiki-link pass,
+    /// which resolves against a single map per page.
     fn resolved_for(&self, source: &str) -> HashMap<String, String> {
-        let name = source_of(source).map(|s| s.name).unwrap_or_default();
-        let mut map = self.global.resolved.clone();
-        if let Some(local) = self.local.get(name.as_str()) {
-            for (key, route) in &local.resolved {
-                map.insert(key.clone(), route.clone());
+        let mut map = HashMap::new();
+        for (local, global) in self.namespaces(source).into_iter().rev() {
+            for namespace in [global, local].into_iter().flatten() {
+                for (key, route) in &namespace.resolved {
+                    map.insert(key.clone(), route.clone());
+                }
             }
         }
         map
@@ -908,11 +1235,13 @@ impl WikiLinkIndex {
     /// Ambiguity candidates for a key as seen from `source` (page's local
     /// namespace first, then global) — for the "ambiguous vs missing" diagnostic.
     fn ambiguity(&self, source: &str, key: &str) -> Option<&Vec<String>> {
-        let name = source_of(source).map(|s| s.name).unwrap_or_default();
-        self.local
-            .get(name.as_str())
-            .and_then(|m| m.ambiguous.get(key))
-            .or_else(|| self.global.ambiguous.get(key))
+        self.namespaces(source)
+            .into_iter()
+            .find_map(|(local, global)| {
+                local
+                    .and_then(|m| m.ambiguous.get(key))
+                    .or_else(|| global.and_then(|m| m.ambiguous.get(key)))
+            })
     }
 }
 
@@ -930,16 +1259,20 @@ fn strip_mount_segment<'a>(trimmed_route: &'a str, seg: &str) -> &'a str {
     }
 }
 
-/// Register a route's wiki-link candidates in both namespaces: source-relative
-/// identifiers (title, leaf slug, the source-relative path and its suffixes) in
-/// the page's own `local` namespace (keyed by source name), and the
-/// **name-qualified** path + suffixes in `global` (so a source is linkable by
-/// name regardless of where it is mounted — `[[<name>:slug]]`).
+/// Register a route's wiki-link candidates in both namespaces of its language
+/// `lang`: source-relative identifiers (title, leaf slug, the source-relative
+/// path and its suffixes) in the page's own `local` namespace (keyed by
+/// language and source name), and the **name-qualified** path + suffixes in
+/// `global` (so a source is linkable by name regardless of where it is mounted
+/// — `[[<name>:slug]]`). Paths are taken without the language prefix, so a
+/// translation answers to the same paths as its original.
 fn add_provenance_candidates(
-    local: &mut HashMap<String, HashMap<String, Vec<String>>>,
+    local: &mut HashMap<(String, String), HashMap<String, Vec<String>>>,
     global: &mut HashMap<String, Vec<String>>,
+    lang: &str,
     title: &str,
     route: &Route,
+    languages: &[crate::config::Language],
 ) {
     let source = source_of(route.as_str());
     let name = source.as_ref().map(|s| s.name.clone()).unwrap_or_default();
@@ -947,10 +1280,10 @@ fn add_provenance_candidates(
         .as_ref()
         .map(|s| s.mount.trim_matches('/').to_string())
         .unwrap_or_default();
-    let trimmed = route.as_str().trim_matches('/');
+    let trimmed = crate::i18n::strip_language_prefix(route.as_str(), languages).trim_matches('/');
     let local_path = strip_mount_segment(trimmed, &seg).to_string();
 
-    let local_map = local.entry(name.clone()).or_default();
+    let local_map = local.entry((lang.to_string(), name.clone())).or_default();
     add_wiki_route_candidates(local_map, title, route);
     add_wiki_route_candidates(local_map, &local_path, route);
     if let Some(slug) = route_leaf_slug(route) {
@@ -1380,9 +1713,10 @@ pub async fn render_page<DB: Db>(
         .pages
         .get(&route)
         .expect("Page not found for route");
+    let related = crate::related::related_pages(db, route.clone()).await?;
 
     // Render via the statically linked gingembre renderer.
-    match render_page_template(page, &site_tree, templates, can_edit).await {
+    match render_page_template(page, &related, &site_tree, templates, can_edit).await {
         Ok(html) => {
             let html = resolve_shortcodes(html, &all_templates, &site_tree).await;
             Ok(Ok(RenderedHtml(html)))
@@ -1442,8 +1776,13 @@ pub async fn render_page_markdown<DB: Db>(
 
     let mut blocks = marq::parse_ast(stripped.body);
     let source_route_map = source_to_route_map(db).await?;
+    let languages = crate::i18n::site_languages(db).await?;
+    let source_route_map = match crate::i18n::language_of(&source_path, &languages) {
+        Some(lang) => crate::i18n::localized_source_map(&source_route_map, &lang.code, &languages),
+        None => source_route_map,
+    };
     let route_markdown_map = markdown_route_map(&site_tree);
-    let wiki_link_index = WikiLinkIndex::build(&site_tree);
+    let wiki_link_index = WikiLinkIndex::build(&site_tree, languages.clone());
 
     rewrite_markdown_blocks(
         &mut blocks,
@@ -1518,6 +1857,56 @@ pub async fn render_section<DB: Db>(
     }
 }
 
+/// Render a later pager of a paginated section (`/blog/page/2`) - tracked.
+/// Same template and dependencies as `render_section`, with `paginator`
+/// pointing at this pager's slice of the listing.
+#[picante::tracked]
+#[tracing::instrument(skip_all, name = "render_section_pager", fields(route = %route))]
+pub async fn render_section_pager<DB: Db>(
+    db: &DB,
+    route: Route,
+    can_edit: bool,
+) -> PicanteResult<Result<RenderedHtml, SiteError>> {
+    use crate::render::{Renderable, try_render_template};
+    use crate::shortcode::resolve_shortcodes;
+
+    let site_tree = match build_tree(db).await? {
+        Ok(tree) => tree,
+        Err(errors) => return Ok(Err(BuildError { errors }.into())),
+    };
+
+    let all_templates = load_all_templates(db).await?;
+    let templates = templates_for_route(all_templates.clone(), route.as_str());
+
+    let pager = site_tree
+        .pagers
+        .get(&route)
+        .expect("Pager not found for route");
+    let section = site_tree
+        .sections
+        .get(&pager.section)
+        .expect("Section not found for pager");
+
+    match try_render_template(
+        Renderable::SectionPager(section, pager),
+        &site_tree,
+        templates,
+        can_edit,
+    )
+    .await
+    {
+        Ok(html) => {
+            let html = resolve_shortcodes(html, &all_templates, &site_tree).await;
+            Ok(Ok(RenderedHtml(html)))
+        }
+        Err(error) => Ok(Err(RenderError {
+            route: route.clone(),
+            error,
+        }
+        .into())),
+    }
+}
+
 /// Load a single static file's content - tracked
 #[picante::tracked]
 pub async fn load_static<DB: Db>(db: &DB, file: StaticFile) -> PicanteResult<Vec<u8>> {
@@ -2053,27 +2442,7 @@ pub async fn build_site<DB: Db>(db: &DB) -> PicanteResult<Result<SiteOutput, Sit
     // --- Phase 1: Render all HTML pages using serve_html ---
     // This reuses the exact same pipeline as `ddc serve`, ensuring consistency.
     // The static build is viewer-independent, so render anonymously (`can_edit = false`).
-    for route in site_tree.sections.keys() {
-        match serve_html(db, route.clone(), false).await? {
-            Ok(Some(served)) => {
-                // Extract links using HTML cell (proper parser, not regex)
-                let extracted = crate::cells::extract_links_from_html(served.html.clone())
-                    .await
-                    .unwrap_or_default();
-                files.push(OutputFile::Html {
-                    route: route.clone(),
-                    content: served.html,
-                    head_injections: served.head_injections,
-                    hrefs: extracted.hrefs,
-                    element_ids: extracted.element_ids,
-                });
-            }
-            Ok(None) => {}
-            Err(e) => return Ok(Err(e)),
-        }
-    }
-
-    for route in site_tree.pages.keys() {
+    for route in site_tree.html_routes() {
         match serve_html(db, route.clone(), false).await? {
             Ok(Some(served)) => {
                 // Extract links using HTML cell (proper parser, not regex)
@@ -2196,6 +2565,15 @@ pub async fn build_site<DB: Db>(db: &DB) -> PicanteResult<Result<SiteOutput, Sit
     files.extend(crate::search::runtime_output_files());
     files.extend(crate::search::search_index_files(db).await?);
 
+    // --- Phase 6: Section feeds (Atom / RSS / JSON Feed) ---
+    files.extend(crate::feeds::feed_files(db).await?);
+
+    // --- Phase 7: sitemap.xml and robots.txt ---
+    files.extend(crate::sitemap::sitemap_files(db).await?);
+
+    // --- Phase 8: Redirect stubs for aliases and site.redirects ---
+    files.extend(crate::redirects::redirect_files(db).await?);
+
     Ok(Ok(SiteOutput {
         files,
         code_execution_results,
@@ -2228,7 +2606,23 @@ pub async fn all_rendered_html<DB: Db>(
     // Get the source-to-route map for internal link resolution
     // This creates dependencies on all source files via parse_file
     let source_route_map = source_to_route_map(db).await?;
-    let wiki_link_index = WikiLinkIndex::build(&site_tree);
+    // `@/` links from translated content land on translations when they exist.
+    let languages = crate::i18n::site_languages(db).await?;
+    let localized_route_maps: HashMap<&str, HashMap<String, String>> = languages
+        .iter()
+        .map(|lang| {
+            (
+                lang.code.as_str(),
+                crate::i18n::localized_source_map(&source_route_map, &lang.code, &languages),
+            )
+        })
+        .collect();
+    let source_route_map_for = |route: &Route| {
+        crate::i18n::language_of_route(route.as_str(), &languages)
+            .and_then(|lang| localized_route_maps.get(lang.code.as_str()))
+            .unwrap_or(&source_route_map)
+    };
+    let wiki_link_index = WikiLinkIndex::build(&site_tree, languages.clone());
     let mut unresolved_wiki_links = Vec::new();
 
     let mut pages = HashMap::new();
@@ -2253,7 +2647,7 @@ pub async fn all_rendered_html<DB: Db>(
         let html = resolve_shortcodes(html, &template_map, &site_tree).await;
         // Resolve relative links based on section route, then @/ links
         let html = resolve_relative_links(&html, route.as_str()).await;
-        let html = resolve_internal_links(&html, &source_route_map).await;
+        let html = resolve_internal_links(&html, source_route_map_for(route)).await;
         let resolved =
             resolve_wiki_links(&html, &wiki_link_index.resolved_for(route.as_str())).await;
         collect_wiki_link_errors(
@@ -2270,7 +2664,8 @@ pub async fn all_rendered_html<DB: Db>(
         // Anonymous (`can_edit = false`): viewer-independent aggregate (font
         // subsetting). Narrow to the source serving this route for own-chrome.
         let templates = templates_for_route(template_map.clone(), route.as_str());
-        let html = match render_page_template(page, &site_tree, templates, false).await {
+        let related = crate::related::related_pages(db, route.clone()).await?;
+        let html = match render_page_template(page, &related, &site_tree, templates, false).await {
             Ok(html) => html,
             Err(error) => {
                 return Ok(Err(RenderError {
@@ -2284,7 +2679,7 @@ pub async fn all_rendered_html<DB: Db>(
         let html = resolve_shortcodes(html, &template_map, &site_tree).await;
         // Resolve relative links based on the page's section route, then @/ links
         let html = resolve_relative_links(&html, page.section_route.as_str()).await;
-        let html = resolve_internal_links(&html, &source_route_map).await;
+        let html = resolve_internal_links(&html, source_route_map_for(route)).await;
         let resolved =
             resolve_wiki_links(&html, &wiki_link_index.resolved_for(route.as_str())).await;
         collect_wiki_link_errors(
@@ -2297,6 +2692,26 @@ pub async fn all_rendered_html<DB: Db>(
         pages.insert(route.clone(), html);
     }
 
+    // Section pagers and generated taxonomy pages render through their own
+    // memoized queries.
+    for route in site_tree.pagers.keys() {
+        match render_section_pager(db, route.clone(), false).await? {
+            Ok(RenderedHtml(html)) => {
+                pages.insert(route.clone(), html);
+            }
+            Err(e) => return Ok(Err(e)),
+        }
+    }
+    for route in site_tree.taxonomy_routes() {
+        match crate::taxonomy::render_taxonomy_route(db, &site_tree, route, false).await? {
+            Some(Ok(RenderedHtml(html))) => {
+                pages.insert(route.clone(), html);
+            }
+            Some(Err(e)) => return Ok(Err(e)),
+            None => {}
+        }
+    }
+
     if !unresolved_wiki_links.is_empty() {
         tracing::warn!(
             "{}",
@@ -2771,6 +3186,8 @@ pub async fn static_url_map<DB: Db>(db: &DB) -> PicanteResult<HashMap<String, St
 /// recover both the mount (for asset prefixing) and the name (for wiki links).
 fn source_of(path: &str) -> Option<crate::config::ResolvedSource> {
     let cfg = crate::config::global_config()?;
+    // Translations live under a language prefix but belong to the same source.
+    let path = crate::i18n::strip_language_prefix(path, &cfg.languages);
     let trimmed = path.trim_matches('/');
     let mut best: Option<&crate::config::ResolvedSource> = None;
     let mut best_len: Option<usize> = None;
@@ -3300,10 +3717,36 @@ pub async fn serve_html<DB: Db>(
             Err(e) => return Ok(Err(e)),
         };
         (head, html, base)
+    } else if let Some(pager) = site_tree.pagers.get(&route) {
+        // A pager is the section's listing again: same head, same link base.
+        let head = site_tree
+            .sections
+            .get(&pager.section)
+            .map(|s| s.head_injections.clone())
+            .unwrap_or_default();
+        let base = pager.section.as_str().to_string();
+        let html = match render_section_pager(db, route.clone(), can_edit).await? {
+            Ok(RenderedHtml(html)) => html,
+            Err(e) => return Ok(Err(e)),
+        };
+        (head, html, base)
     } else {
-        return Ok(Ok(None));
+        match crate::taxonomy::render_taxonomy_route(db, &site_tree, &route, can_edit).await? {
+            Some(Ok(RenderedHtml(html))) => (Vec::new(), html, route.as_str().to_string()),
+            Some(Err(e)) => return Ok(Err(e)),
+            None => return Ok(Ok(None)),
+        }
     };
 
+    // Advertise the section's feeds on the section and on every page in it.
+    let mut head_injections = head_injections;
+    head_injections.extend(crate::feeds::alternate_links(
+        &site_tree,
+        &Route::new(base_route.clone()),
+    ));
+    // And point search engines at the other language versions.
+    head_injections.extend(crate::i18n::alternate_links(&site_tree, &route));
+
     // Build the full URL rewrite map
     let mut path_map: HashMap<String, String> = HashMap::new();
 
@@ -3441,6 +3884,34 @@ pub async fn serve_html<DB: Db>(
         }
     }
 
+    // Page bundle aliases: `article/index.md` references its co-located
+    // assets relative to itself (`cover.jpg`, `./cover.jpg`). Alias those to
+    // the bundle's cache-busted (and responsive) entries, which live under the
+    // bundle directory's key whatever route the page ends up at.
+    let source_to_route = source_to_route_map(db).await?;
+    if let Some(dir) = source_to_route
+        .iter()
+        .find(|(source, page_route)| {
+            page_route.as_str() == route.as_str()
+                && SourcePath::new(source.to_string()).is_bundle_index()
+        })
+        .map(|(source, _)| source.trim_end_matches("index.md"))
+    {
+        let prefix = format!("/{dir}");
+        for (key, value) in path_map.clone() {
+            if let Some(rest) = key.strip_prefix(&prefix) {
+                path_map.insert(rest.to_string(), value.clone());
+                path_map.insert(format!("./{rest}"), value);
+            }
+        }
+        for (key, value) in image_variants.clone() {
+            if let Some(rest) = key.strip_prefix(&prefix) {
+                image_variants.insert(rest.to_string(), value.clone());
+                image_variants.insert(format!("./{rest}"), value);
+            }
+        }
+    }
+
     // Mount-aware internal links: a mounted source authored its page links as
     // source-root-absolute (`/exec/`, `/exec/#anchor`, `/` for home) — the form
     // it would use standalone. The html cell rewrites the *path* portion of
@@ -3465,7 +3936,11 @@ pub async fn serve_html<DB: Db>(
     // - Transform <img> to <picture> for responsive images
     // The link indexes are parse-derived: source_to_route_map (memoized) and a
     // per-page wiki map from WikiLinkIndex over the already-built site_tree.
-    let source_to_route = source_to_route_map(db).await?;
+    let languages = crate::i18n::site_languages(db).await?;
+    let source_to_route = match crate::i18n::language_of_route(route.as_str(), &languages) {
+        Some(lang) => crate::i18n::localized_source_map(&source_to_route, &lang.code, &languages),
+        None => source_to_route,
+    };
     // Context-first wikilink resolution (#8): resolve every raw target this page
     // references against its own section first (then ancestors, then the source).
     // Keyed by the *raw* target so the path / `./` / `@/` / `source:` forms
@@ -3498,7 +3973,8 @@ pub async fn serve_html<DB: Db>(
                     .collect::<Vec<_>>()
             })
             .unwrap_or_default(),
-    );
+    )
+    .with_language_prefixes(languages.iter().skip(1).map(|l| l.prefix.clone()));
     let mut wiki_to_route: HashMap<String, String> = HashMap::new();
     let mut wiki_to_title: HashMap<String, String> = HashMap::new();
     for raw in extract_wiki_targets(&raw_html) {
@@ -3863,3 +4339,16 @@ mod wiki_suffix_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod slug_tests {
+    use super::validate_slug;
+
+    #[test]
+    fn slug_must_be_one_segment_on_a_page() {
+        assert!(validate_slug("hello", false).is_ok());
+        assert!(validate_slug("/hello/", false).is_ok());
+        assert!(validate_slug("2024/hello", false).is_err());
+        assert!(validate_slug("posts", true).is_err());
+    }
+}
@@ -0,0 +1,173 @@
+//! Permanent redirects: page `aliases` and the `site.redirects` map.
+//!
+//! `build_tree` collects both into [`SiteTree::redirects`]. `ddc serve` answers
+//! those routes with a 301; `ddc build` writes a meta-refresh stub at each old
+//! route plus a `_redirects` file for hosts that read one (Netlify,
+//! Cloudflare Pages). The stubs are HTML outputs, so link checking sees the
+//! old routes as valid targets and checks where they point.
+
+use std::collections::BTreeMap;
+
+use picante::PicanteResult;
+
+use crate::cells::MarkdownParseError;
+use crate::db::{Db, OutputFile, ParsedData, SiteTree};
+use crate::queries::build_tree;
+use crate::types::{Route, StaticPath};
+
+/// File listing every redirect, in the `_redirects` format static hosts read.
+const REDIRECTS_FILE: &str = "_redirects";
+
+/// Read the frontmatter `aliases` list into routes.
+pub fn parse_aliases(aliases: &[String]) -> Result<Vec<Route>, MarkdownParseError> {
+    aliases
+        .iter()
+        .map(|alias| {
+            route_for_path(alias).ok_or_else(|| MarkdownParseError {
+                message: format!(
+                    "invalid frontmatter aliases entry '{alias}': expected a site path like /old/path/"
+                ),
+            })
+        })
+        .collect()
+}
+
+/// Route for a site path (`/old/path/` → `/old/path`). `None` for anything
+/// that is not a site path: empty, relative, a URL, or carrying a query.
+fn route_for_path(path: &str) -> Option<Route> {
+    let path = path.trim();
+    if !path.starts_with('/') || path.starts_with("//") || path.contains(['?', '#', ' ']) {
+        return None;
+    }
+    let trimmed = path.trim_end_matches('/');
+    Some(if trimmed.is_empty() {
+        Route::root()
+    } else {
+        Route::new(trimmed.to_string())
+    })
+}
+
+/// Every redirect of the site: page aliases, then `site.redirects` (which
+/// wins on conflict). Routes that render content are never redirected.
+pub fn collect(
+    parsed: &[ParsedData],
+    site_redirects: &BTreeMap<String, String>,
+    tree: &SiteTree,
+) -> BTreeMap<Route, String> {
+    let mut redirects = BTreeMap::new();
+    for data in parsed {
+        for alias in &data.aliases {
+            redirects.insert(alias.clone(), data.route.as_str().to_string());
+        }
+    }
+    for (from, to) in site_redirects {
+        match route_for_path(from) {
+            Some(route) => {
+                redirects.insert(route, to.clone());
+            }
+            None => tracing::warn!(from, "ignoring site.redirects entry: not a site path"),
+        }
+    }
+
+    let rendered: std::collections::HashSet<&Route> = tree.html_routes().collect();
+    redirects.retain(|from, to| !rendered.contains(from) && from.as_str() != to);
+    redirects
+}
+
+/// Meta-refresh stubs at every redirected route plus the `_redirects` file.
+#[picante::tracked]
+pub async fn redirect_files<DB: Db>(db: &DB) -> PicanteResult<Vec<OutputFile>> {
+    let tree = match build_tree(db).await? {
+        Ok(tree) => tree,
+        Err(_) => return Ok(Vec::new()),
+    };
+    if tree.redirects.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut files: Vec<OutputFile> = tree
+        .redirects
+        .iter()
+        .map(|(from, to)| OutputFile::Html {
+            route: from.clone(),
+            content: stub_html(to),
+            head_injections: Vec::new(),
+            hrefs: vec![to.clone()],
+            element_ids: Vec::new(),
+        })
+        .collect();
+    files.push(OutputFile::Static {
+        path: StaticPath::new(REDIRECTS_FILE.to_string()),
+        content: redirects_file(&tree.redirects).into_bytes(),
+    });
+    Ok(files)
+}
+
+/// A page that sends browsers (and crawlers, via `canonical`) to `location`.
+fn stub_html(location: &str) -> String {
+    let location = escape_attr(location);
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Redirecting…</title>\n\
+         <link rel=\"canonical\" href=\"{location}\">\n\
+         <meta name=\"robots\" content=\"noindex\">\n\
+         <meta http-equiv=\"refresh\" content=\"0; url={location}\">\n\
+         </head>\n<body>\n<p>This page has moved to <a href=\"{location}\">{location}</a>.</p>\n</body>\n</html>\n"
+    )
+}
+
+/// `/old /new 301` per line.
+fn redirects_file(redirects: &BTreeMap<Route, String>) -> String {
+    redirects
+        .iter()
+        .map(|(from, to)| format!("{} {to} 301\n", from.as_str()))
+        .collect()
+}
+
+fn escape_attr(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aliases_must_be_site_paths() {
+        let aliases = ["/old/post/", "/"].map(String::from);
+        let routes = parse_aliases(&aliases).unwrap();
+        assert_eq!(routes[0].as_str(), "/old/post");
+        assert_eq!(routes[1], Route::root());
+
+        for bad in ["old/post", "https://example.com/x", "//cdn/x", "/a?b=1"] {
+            assert!(
+                parse_aliases(&[bad.to_string()]).is_err(),
+                "{bad} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn redirects_file_lists_one_redirect_per_line() {
+        let redirects = BTreeMap::from([
+            (Route::new("/a".to_string()), "/b".to_string()),
+            (
+                Route::new("/docs".to_string()),
+                "https://docs.example.com/".to_string(),
+            ),
+        ]);
+        assert_eq!(
+            redirects_file(&redirects),
+            "/a /b 301\n/docs https://docs.example.com/ 301\n"
+        );
+    }
+
+    #[test]
+    fn stub_refreshes_to_the_target() {
+        let html = stub_html("/new?a=1&b=2");
+        assert!(html.contains(r#"<meta http-equiv="refresh" content="0; url=/new?a=1&amp;b=2">"#));
+        assert!(html.contains(r#"<link rel="canonical" href="/new?a=1&amp;b=2">"#));
+    }
+}
@@ -0,0 +1,300 @@
+//! Related pages: `page.related` in templates.
+//!
+//! Each page is represented by the mean of its heading-chunk embeddings (the
+//! vectors [`crate::knowledge`] already caches per source), and its related
+//! pages are its nearest neighbours by cosine similarity. Everything is
+//! computed at build time, so the published site needs no runtime service.
+//!
+//! `site.related` turns it on, sets how many suggestions a page gets (`k`) and
+//! can restrict them to the page's section or to pages sharing one of its
+//! terms in a taxonomy (`within`). A page with `related = false` in its
+//! frontmatter neither gets nor appears in suggestions. [`related_pages`]
+//! returns routes and titles only, so an edit that shifts scores without
+//! changing a page's list leaves its render cached.
+
+use std::collections::BTreeMap;
+
+use eyre::{Result, eyre};
+use facet::Facet;
+use picante::PicanteResult;
+
+use crate::config::TaxonomyDef;
+use crate::db::{ConfigRegistry, Db, Page, SourceRegistry};
+use crate::knowledge::{Embedding, centroid, page_chunks_embedded};
+use crate::queries::{build_tree, parse_file};
+use crate::types::{Route, Title};
+
+/// Suggestions per page when `site.related` doesn't set `k`.
+pub const DEFAULT_K: usize = 5;
+
+/// `site.related` with its defaults filled in.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct RelatedSettings {
+    pub k: usize,
+    pub within: RelatedScope,
+}
+
+/// Which pages may be suggested for a page.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+#[repr(u8)]
+pub enum RelatedScope {
+    /// Any page of the site
+    Site,
+    /// Pages of the same section
+    Section,
+    /// Pages sharing a term of this taxonomy
+    Taxonomy(String),
+}
+
+/// One suggestion.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct RelatedPage {
+    pub route: Route,
+    pub title: Title,
+}
+
+/// A page's position in embedding space: its chunks' mean, unit-normalized.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct PageVector {
+    pub route: Route,
+    pub vector: Embedding,
+}
+
+/// Check `site.related`: `k` is at least 1 and `within` names `section` or a
+/// configured taxonomy.
+pub fn resolve(
+    config: Option<dodeca_config::RelatedConfig>,
+    taxonomies: &BTreeMap<String, TaxonomyDef>,
+) -> Result<Option<RelatedSettings>> {
+    let Some(config) = config else {
+        return Ok(None);
+    };
+    let k = config.k.unwrap_or(DEFAULT_K);
+    if k == 0 {
+        return Err(eyre!("related.k must be at least 1"));
+    }
+    let within = match config.within.as_deref() {
+        None => RelatedScope::Site,
+        Some("section") => RelatedScope::Section,
+        Some(name) if taxonomies.contains_key(name) => RelatedScope::Taxonomy(name.to_string()),
+        Some(name) => {
+            return Err(eyre!(
+                "related.within `{name}` is neither `section` nor a configured taxonomy"
+            ));
+        }
+    };
+    Ok(Some(RelatedSettings { k, within }))
+}
+
+/// The configured settings. Tracked on the config input so only a
+/// `site.related` edit re-ranks pages.
+#[picante::tracked]
+pub async fn site_related<DB: Db>(db: &DB) -> PicanteResult<Option<RelatedSettings>> {
+    Ok(ConfigRegistry::config(db)?.and_then(|c| c.related.clone()))
+}
+
+/// One vector per page taking part in suggestions: pages (not sections) that
+/// haven't opted out and have embeddable text.
+#[picante::tracked]
+pub async fn page_vectors<DB: Db>(db: &DB) -> PicanteResult<Vec<PageVector>> {
+    let sources = SourceRegistry::sources(db)?.unwrap_or_default();
+    let mut vectors = Vec::new();
+    for source in sources.iter() {
+        let Ok(data) = parse_file(db, *source).await? else {
+            continue;
+        };
+        if data.is_section || !data.related {
+            continue;
+        }
+        let chunks = page_chunks_embedded(db, *source).await?;
+        if let Some(mut values) = centroid(&chunks) {
+            normalize(&mut values);
+            vectors.push(PageVector {
+                route: data.route,
+                vector: Embedding { values },
+            });
+        }
+    }
+    vectors.sort_by(|a, b| a.route.cmp(&b.route));
+    Ok(vectors)
+}
+
+/// `route`'s related pages, most similar first. Empty unless `site.related`
+/// is configured.
+#[picante::tracked]
+pub async fn related_pages<DB: Db>(db: &DB, route: Route) -> PicanteResult<Vec<RelatedPage>> {
+    let Some(settings) = site_related(db).await? else {
+        return Ok(Vec::new());
+    };
+    let Ok(tree) = build_tree(db).await? else {
+        return Ok(Vec::new());
+    };
+    let Some(page) = tree.pages.get(&route) else {
+        return Ok(Vec::new());
+    };
+    let vectors = page_vectors(db).await?;
+    let Some(own) = vectors.iter().find(|v| v.route == route) else {
+        return Ok(Vec::new());
+    };
+    let candidates = vectors.iter().filter_map(|v| {
+        let candidate = tree.pages.get(&v.route)?;
+        in_scope(page, candidate, &settings.within).then_some((candidate, &v.vector))
+    });
+    Ok(rank(&own.vector, candidates, settings.k))
+}
+
+/// Whether `candidate` may be suggested on `page`: another page in the same
+/// language, inside the configured scope.
+fn in_scope(page: &Page, candidate: &Page, scope: &RelatedScope) -> bool {
+    if candidate.route == page.route || candidate.lang != page.lang {
+        return false;
+    }
+    match scope {
+        RelatedScope::Site => true,
+        RelatedScope::Section => candidate.section_route == page.section_route,
+        RelatedScope::Taxonomy(name) => {
+            let slugs = |p: &Page| -> Vec<String> {
+                p.taxonomies
+                    .get(name)
+                    .into_iter()
+                    .flatten()
+                    .map(|term| marq::slugify(term))
+                    .collect()
+            };
+            let own = slugs(page);
+            slugs(candidate).iter().any(|slug| own.contains(slug))
+        }
+    }
+}
+
+/// The `k` candidates nearest to `own`; ties go to the lower route so the
+/// order is stable.
+fn rank<'a>(
+    own: &Embedding,
+    candidates: impl Iterator<Item = (&'a Page, &'a Embedding)>,
+    k: usize,
+) -> Vec<RelatedPage> {
+    let mut scored: Vec<(f32, &Page)> = candidates
+        .map(|(page, vector)| (own.dot(&vector.values), page))
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| a.1.route.cmp(&b.1.route)));
+    scored
+        .into_iter()
+        .take(k)
+        .map(|(_, page)| RelatedPage {
+            route: page.route.clone(),
+            title: page.title.clone(),
+        })
+        .collect()
+}
+
+fn normalize(values: &mut [f32]) {
+    let norm = values.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for v in values {
+            *v /= norm;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::HtmlBody;
+    use facet_value::Value;
+
+    fn page(route: &str, section: &str, tags: &[&str]) -> Page {
+        Page {
+            route: Route::new(route.to_string()),
+            title: Title::new(route.trim_start_matches('/').to_string()),
+            weight: 0,
+            body_html: HtmlBody::from_static(""),
+            section_route: Route::new(section.to_string()),
+            headings: Vec::new(),
+            rules: Vec::new(),
+            source_map: Default::default(),
+            head_injections: Vec::new(),
+            last_updated: 0,
+            date: None,
+            updated: None,
+            draft: false,
+            sitemap: Default::default(),
+            series: None,
+            lang: None,
+            taxonomies: BTreeMap::from([(
+                "tags".to_string(),
+                tags.iter().map(|t| t.to_string()).collect(),
+            )]),
+            extra: Value::default(),
+            template: None,
+        }
+    }
+
+    fn vector(values: &[f32]) -> Embedding {
+        let mut values = values.to_vec();
+        normalize(&mut values);
+        Embedding { values }
+    }
+
+    #[test]
+    fn ranks_nearest_first_and_keeps_k() {
+        let a = page("/a", "/", &[]);
+        let b = page("/b", "/", &[]);
+        let c = page("/c", "/", &[]);
+        let (vb, vc) = (vector(&[0.9, 0.1]), vector(&[0.1, 0.9]));
+        let own = vector(&[1.0, 0.0]);
+
+        let ranked = rank(&own, [(&c, &vc), (&b, &vb)].into_iter(), 5);
+        let routes: Vec<&str> = ranked.iter().map(|r| r.route.as_str()).collect();
+        assert_eq!(routes, ["/b", "/c"]);
+
+        let ranked = rank(&own, [(&a, &vb), (&b, &vb)].into_iter(), 1);
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].route.as_str(), "/a", "ties go to the lower route");
+    }
+
+    #[test]
+    fn scope_limits_candidates() {
+        let post = page("/blog/a", "/blog", &["Rust", "async"]);
+        let sibling = page("/blog/b", "/blog", &["go"]);
+        let tagged = page("/docs/c", "/docs", &["rust"]);
+
+        assert!(!in_scope(&post, &post, &RelatedScope::Site));
+        assert!(in_scope(&post, &tagged, &RelatedScope::Site));
+
+        assert!(in_scope(&post, &sibling, &RelatedScope::Section));
+        assert!(!in_scope(&post, &tagged, &RelatedScope::Section));
+
+        let tags = RelatedScope::Taxonomy("tags".to_string());
+        assert!(in_scope(&post, &tagged, &tags), "terms match by slug");
+        assert!(!in_scope(&post, &sibling, &tags));
+    }
+
+    #[test]
+    fn resolve_checks_k_and_scope() {
+        let taxonomies = BTreeMap::from([("tags".to_string(), TaxonomyDef::default())]);
+        let config = |k: Option<usize>, within: Option<&str>| {
+            Some(dodeca_config::RelatedConfig {
+                k,
+                within: within.map(str::to_string),
+            })
+        };
+
+        assert_eq!(resolve(None, &taxonomies).unwrap(), None);
+        assert_eq!(
+            resolve(config(None, None), &taxonomies).unwrap(),
+            Some(RelatedSettings {
+                k: DEFAULT_K,
+                within: RelatedScope::Site
+            })
+        );
+        assert_eq!(
+            resolve(config(Some(3), Some("tags")), &taxonomies)
+                .unwrap()
+                .map(|s| s.within),
+            Some(RelatedScope::Taxonomy("tags".to_string()))
+        );
+        assert!(resolve(config(Some(0), None), &taxonomies).is_err());
+        assert!(resolve(config(None, Some("categories")), &taxonomies).is_err());
+    }
+}
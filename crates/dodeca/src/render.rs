@@ -2,16 +2,18 @@ use crate::cells::{
     inject_code_buttons as inject_code_buttons_direct, render_template as render_template_direct,
 };
 use crate::db::{
-    CodeExecutionMetadata, CodeExecutionResult, DependencySourceInfo, Heading, Page, Section,
-    SiteTree,
+    CodeExecutionMetadata, CodeExecutionResult, DependencySourceInfo, Heading, Page, Pager,
+    Section, SiteTree, Taxonomy,
 };
+use crate::related::RelatedPage;
+use crate::taxonomy::TermListing;
 use crate::template_host::{RenderContext, RenderContextGuard};
 use crate::types::Route;
 use crate::url_rewrite::mark_dead_links;
 use facet_value::{DestructuredRef, VArray, VObject, VString, Value};
 use gingembre::ValueExt;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 
 // Re-export for backwards compatibility
@@ -568,21 +570,29 @@ pub async fn inject_livereload_with_build_info(
 // Template rendering through the statically linked gingembre renderer.
 // ============================================================================
 
-/// Something that can be rendered (page or section)
+/// Something that can be rendered (page, section or generated taxonomy page)
 pub enum Renderable<'a> {
-    Page(&'a Page),
+    /// A page with its related pages (`page.related`)
+    Page(&'a Page, &'a [RelatedPage]),
     Section(&'a Section),
+    /// A later page of a paginated section's listing
+    SectionPager(&'a Section, &'a Pager),
+    /// A taxonomy's index page, listing its terms
+    Taxonomy(&'a Taxonomy),
+    /// A term page, listing the pages tagged with it
+    TaxonomyTerm(&'a TermListing),
 }
 
 impl<'a> Renderable<'a> {
     /// Get the template name for this renderable
     fn template_name(&self) -> &str {
         match self {
-            Renderable::Page(page) => page.template.as_deref().unwrap_or("page.html"),
-            Renderable::Section(section) => section
+            Renderable::Page(page, _) => page.template.as_deref().unwrap_or("page.html"),
+            Renderable::Section(section) | Renderable::SectionPager(section, _) => section
                 .template
                 .as_deref()
                 .unwrap_or_else(|| self.default_template_name()),
+            Renderable::Taxonomy(_) | Renderable::TaxonomyTerm(_) => self.default_template_name(),
         }
     }
 
@@ -590,17 +600,26 @@ impl<'a> Renderable<'a> {
     /// Used as a fallback when a page names a template that doesn't exist.
     fn default_template_name(&self) -> &'static str {
         match self {
-            Renderable::Page(_) => "page.html",
-            Renderable::Section(section) if section.route.as_str() == "/" => "index.html",
-            Renderable::Section(_) => "section.html",
+            Renderable::Page(..) => "page.html",
+            Renderable::Section(section) | Renderable::SectionPager(section, _)
+                if section.route.as_str() == "/" =>
+            {
+                "index.html"
+            }
+            Renderable::Section(_) | Renderable::SectionPager(..) => "section.html",
+            Renderable::Taxonomy(_) => "taxonomy.html",
+            Renderable::TaxonomyTerm(_) => "taxonomy_term.html",
         }
     }
 
     /// Get the route for this renderable
     fn route(&self) -> &Route {
         match self {
-            Renderable::Page(page) => &page.route,
+            Renderable::Page(page, _) => &page.route,
             Renderable::Section(section) => &section.route,
+            Renderable::SectionPager(_, pager) => &pager.route,
+            Renderable::Taxonomy(taxonomy) => &taxonomy.route,
+            Renderable::TaxonomyTerm(listing) => &listing.term.route,
         }
     }
 
@@ -636,8 +655,15 @@ impl<'a> Renderable<'a> {
 
         // Add page and section based on what we're rendering
         match self {
-            Renderable::Page(page) => {
-                obj.insert(VString::from("page"), page_to_value(page, site_tree));
+            Renderable::Page(page, related) => {
+                let mut page_value = page_to_value(page, site_tree);
+                if let Some(map) = page_value.as_object_mut() {
+                    map.insert(
+                        VString::from("related"),
+                        VArray::from_iter(related.iter().map(|r| related_to_value(r, &base_url))),
+                    );
+                }
+                obj.insert(VString::from("page"), page_value);
                 // Find and add parent section
                 if let Some(section) = find_parent_section(&page.route, site_tree) {
                     obj.insert(
@@ -652,6 +678,46 @@ impl<'a> Renderable<'a> {
                     VString::from("section"),
                     section_to_value(section, site_tree, &base_url),
                 );
+                if section.paginate_by.is_some() {
+                    obj.insert(
+                        VString::from("paginator"),
+                        paginator_to_value(section, 1, site_tree, &base_url),
+                    );
+                }
+            }
+            Renderable::SectionPager(section, pager) => {
+                obj.insert(VString::from("page"), Value::NULL);
+                obj.insert(
+                    VString::from("section"),
+                    section_to_value(section, site_tree, &base_url),
+                );
+                obj.insert(
+                    VString::from("paginator"),
+                    paginator_to_value(section, pager.index, site_tree, &base_url),
+                );
+            }
+            Renderable::Taxonomy(taxonomy) => {
+                obj.insert(VString::from("page"), Value::NULL);
+                obj.insert(
+                    VString::from("taxonomy"),
+                    taxonomy_to_value(taxonomy, &base_url),
+                );
+            }
+            Renderable::TaxonomyTerm(listing) => {
+                obj.insert(VString::from("page"), Value::NULL);
+                obj.insert(
+                    VString::from("taxonomy"),
+                    Value::from(taxonomy_header(
+                        &listing.taxonomy_name,
+                        listing.taxonomy_title.as_str(),
+                        &listing.taxonomy_route,
+                        &base_url,
+                    )),
+                );
+                obj.insert(
+                    VString::from("term"),
+                    term_to_value(listing, site_tree, &base_url),
+                );
             }
         }
 
@@ -806,11 +872,18 @@ pub async fn try_render_template(
 /// Render page template and return a structured error on failure.
 pub async fn render_page_template(
     page: &Page,
+    related: &[RelatedPage],
     site_tree: &SiteTree,
     templates: HashMap<String, String>,
     can_edit: bool,
 ) -> Result<String, cell_gingembre_proto::TemplateRenderError> {
-    try_render_template(Renderable::Page(page), site_tree, templates, can_edit).await
+    try_render_template(
+        Renderable::Page(page, related),
+        site_tree,
+        templates,
+        can_edit,
+    )
+    .await
 }
 
 /// Render section template and return a structured error on failure.
@@ -929,17 +1002,19 @@ fn build_toc_subtree(headings: &[Heading], start: usize, parent_level: u8) -> (V
 }
 
 /// Build the ancestor chain for a page (ordered from root to immediate parent)
-/// Note: The content root ("/") is excluded from ancestors to avoid noisy breadcrumbs.
+/// Note: The content root ("/") and language homes ("/fr") are excluded from
+/// ancestors to avoid noisy breadcrumbs.
 fn build_ancestors(section_route: &Route, site_tree: &SiteTree) -> Vec<Value> {
     let mut ancestors = Vec::new();
     let mut current = section_route.clone();
     let base_url = get_base_url();
+    let languages = crate::i18n::languages();
 
     // Walk up the route hierarchy, collecting all ancestor sections
     loop {
         if let Some(section) = site_tree.sections.get(&current) {
-            // Skip the content root ("/") - it's not useful in breadcrumbs
-            if section.route.as_str() != "/" {
+            // Skip the content roots - they're not useful in breadcrumbs
+            if !crate::i18n::is_home(section.route.as_str(), &languages) {
                 let mut ancestor_map = VObject::new();
                 ancestor_map.insert(VString::from("title"), Value::from(section.title.as_str()));
                 ancestor_map.insert(
@@ -963,6 +1038,214 @@ fn build_ancestors(section_route: &Route, site_tree: &SiteTree) -> Vec<Value> {
     ancestors
 }
 
+/// Add frontmatter `date`/`updated` (Unix seconds, omitted when unset) and `draft`.
+fn insert_publishing_fields(
+    map: &mut VObject,
+    date: Option<i64>,
+    updated: Option<i64>,
+    draft: bool,
+) {
+    if let Some(date) = date {
+        map.insert(VString::from("date"), Value::from(date));
+    }
+    if let Some(updated) = updated {
+        map.insert(VString::from("updated"), Value::from(updated));
+    }
+    map.insert(VString::from("draft"), Value::from(draft));
+}
+
+/// A page's terms as `{ taxonomy: [term, ...] }`.
+fn taxonomies_to_value(taxonomies: &BTreeMap<String, Vec<String>>) -> Value {
+    let mut map = VObject::new();
+    for (name, terms) in taxonomies {
+        map.insert(
+            VString::from(name.as_str()),
+            VArray::from_iter(terms.iter().map(|t| Value::from(t.as_str()))),
+        );
+    }
+    map.into()
+}
+
+/// `{ name, title, path, permalink }` for a taxonomy.
+fn taxonomy_header(name: &str, title: &str, route: &Route, base_url: &str) -> VObject {
+    let mut map = VObject::new();
+    map.insert(VString::from("name"), Value::from(name));
+    map.insert(VString::from("title"), Value::from(title));
+    map.insert(VString::from("path"), Value::from(route.as_str()));
+    map.insert(
+        VString::from("permalink"),
+        Value::from(make_permalink(base_url, route.as_str()).as_str()),
+    );
+    map
+}
+
+/// Convert a Taxonomy to a Value for its index page. Each term carries its
+/// `page_count` rather than the pages themselves.
+fn taxonomy_to_value(taxonomy: &Taxonomy, base_url: &str) -> Value {
+    let mut map = taxonomy_header(
+        &taxonomy.name,
+        taxonomy.title.as_str(),
+        &taxonomy.route,
+        base_url,
+    );
+    let terms: Vec<Value> = taxonomy
+        .terms
+        .values()
+        .map(|term| {
+            let mut term_map = VObject::new();
+            term_map.insert(VString::from("name"), Value::from(term.name.as_str()));
+            term_map.insert(VString::from("slug"), Value::from(term.slug.as_str()));
+            term_map.insert(VString::from("path"), Value::from(term.route.as_str()));
+            term_map.insert(
+                VString::from("permalink"),
+                Value::from(make_permalink(base_url, term.route.as_str()).as_str()),
+            );
+            term_map.insert(
+                VString::from("page_count"),
+                Value::from(term.pages.len() as i64),
+            );
+            term_map.into()
+        })
+        .collect();
+    map.insert(VString::from("terms"), VArray::from_iter(terms));
+    map.into()
+}
+
+/// Convert a term listing to a Value, with its pages in full (newest first).
+fn term_to_value(listing: &TermListing, site_tree: &SiteTree, base_url: &str) -> Value {
+    let term = &listing.term;
+    let mut map = VObject::new();
+    map.insert(VString::from("name"), Value::from(term.name.as_str()));
+    map.insert(VString::from("slug"), Value::from(term.slug.as_str()));
+    map.insert(VString::from("path"), Value::from(term.route.as_str()));
+    map.insert(
+        VString::from("permalink"),
+        Value::from(make_permalink(base_url, term.route.as_str()).as_str()),
+    );
+    map.insert(
+        VString::from("pages"),
+        VArray::from_iter(listing.pages.iter().map(|p| page_to_value(p, site_tree))),
+    );
+    map.into()
+}
+
+/// `{ title, path, permalink, part }` for one part of a series.
+fn series_part_to_value(page: &Page, base_url: &str) -> Value {
+    let mut map = VObject::new();
+    map.insert(VString::from("title"), Value::from(page.title.as_str()));
+    map.insert(VString::from("path"), Value::from(page.route.as_str()));
+    map.insert(
+        VString::from("permalink"),
+        Value::from(make_permalink(base_url, page.route.as_str()).as_str()),
+    );
+    if let Some(series) = &page.series {
+        map.insert(VString::from("part"), Value::from(series.part as i64));
+    }
+    map.into()
+}
+
+/// `{ title, path, permalink }` for one entry of `page.related`.
+fn related_to_value(related: &RelatedPage, base_url: &str) -> Value {
+    let mut map = VObject::new();
+    map.insert(VString::from("title"), Value::from(related.title.as_str()));
+    map.insert(VString::from("path"), Value::from(related.route.as_str()));
+    map.insert(
+        VString::from("permalink"),
+        Value::from(make_permalink(base_url, related.route.as_str()).as_str()),
+    );
+    map.into()
+}
+
+/// `page.series`: `{ name, part, index, total, pages, prev, next }`, where
+/// `index` is the 1-based position among the parts and `prev`/`next` are
+/// `null` at either end. `None` for pages outside any series.
+fn page_series_to_value(page: &Page, site_tree: &SiteTree, base_url: &str) -> Option<Value> {
+    let (series, index) = crate::series::position(site_tree, page)?;
+    let part_at = |i: usize| {
+        let route = series.pages.get(i)?;
+        let part = site_tree.pages.get(route)?;
+        Some(series_part_to_value(part, base_url))
+    };
+
+    let mut map = VObject::new();
+    map.insert(VString::from("name"), Value::from(series.name.as_str()));
+    if let Some(membership) = &page.series {
+        map.insert(VString::from("part"), Value::from(membership.part as i64));
+    }
+    map.insert(VString::from("index"), Value::from(index as i64 + 1));
+    map.insert(
+        VString::from("total"),
+        Value::from(series.pages.len() as i64),
+    );
+    map.insert(
+        VString::from("pages"),
+        VArray::from_iter((0..series.pages.len()).filter_map(part_at)),
+    );
+    map.insert(
+        VString::from("prev"),
+        index
+            .checked_sub(1)
+            .and_then(part_at)
+            .unwrap_or(Value::NULL),
+    );
+    map.insert(
+        VString::from("next"),
+        part_at(index + 1).unwrap_or(Value::NULL),
+    );
+    Some(map.into())
+}
+
+/// `page.translations` / `section.translations`: every language version of
+/// `route`, itself included, as `{ lang, name, title, path, permalink }`.
+/// Empty for untranslated content.
+fn translations_to_value(route: &Route, site_tree: &SiteTree, base_url: &str) -> Value {
+    let Some(versions) = site_tree.translations.get(route) else {
+        return VArray::new().into();
+    };
+    let languages = crate::i18n::languages();
+    VArray::from_iter(versions.iter().map(|version| {
+        let title = site_tree
+            .pages
+            .get(&version.route)
+            .map(|p| &p.title)
+            .or_else(|| site_tree.sections.get(&version.route).map(|s| &s.title));
+        let name = languages
+            .iter()
+            .find(|l| l.code == version.lang)
+            .map_or(version.lang.as_str(), |l| l.name.as_str());
+
+        let mut map = VObject::new();
+        map.insert(VString::from("lang"), Value::from(version.lang.as_str()));
+        map.insert(VString::from("name"), Value::from(name));
+        map.insert(
+            VString::from("title"),
+            Value::from(title.map_or("", |t| t.as_str())),
+        );
+        map.insert(VString::from("path"), Value::from(version.route.as_str()));
+        map.insert(
+            VString::from("permalink"),
+            Value::from(make_permalink(base_url, version.route.as_str()).as_str()),
+        );
+        Value::from(map)
+    }))
+    .into()
+}
+
+/// `lang` and `translations` for a page or section.
+fn insert_language_fields(
+    map: &mut VObject,
+    lang: Option<&str>,
+    route: &Route,
+    site_tree: &SiteTree,
+    base_url: &str,
+) {
+    map.insert(VString::from("lang"), lang.map_or(Value::NULL, Value::from));
+    map.insert(
+        VString::from("translations"),
+        translations_to_value(route, site_tree, base_url),
+    );
+}
+
 /// Convert a Page to a Value for template context
 pub fn page_to_value(page: &Page, site_tree: &SiteTree) -> Value {
     use facet_value::DestructuredRef;
@@ -1001,6 +1284,22 @@ pub fn page_to_value(page: &Page, site_tree: &SiteTree) -> Value {
         VString::from("last_updated"),
         Value::from(page.last_updated),
     );
+    insert_publishing_fields(&mut map, page.date, page.updated, page.draft);
+    map.insert(
+        VString::from("taxonomies"),
+        taxonomies_to_value(&page.taxonomies),
+    );
+    map.insert(
+        VString::from("series"),
+        page_series_to_value(page, site_tree, &base_url).unwrap_or(Value::NULL),
+    );
+    insert_language_fields(
+        &mut map,
+        page.lang.as_deref(),
+        &page.route,
+        site_tree,
+        &base_url,
+    );
 
     // Extract description from extra.description for Zola compatibility
     let description = match page.extra.destructure_ref() {
@@ -1033,42 +1332,25 @@ pub fn section_to_value(section: &Section, site_tree: &SiteTree, base_url: &str)
         VString::from("last_updated"),
         Value::from(section.last_updated),
     );
+    insert_publishing_fields(&mut map, section.date, section.updated, section.draft);
     map.insert(
         VString::from("ancestors"),
         VArray::from_iter(build_ancestors(&section.route, site_tree)),
     );
+    insert_language_fields(
+        &mut map,
+        section.lang.as_deref(),
+        &section.route,
+        site_tree,
+        base_url,
+    );
 
     // Add pages in this section (sorted by weight, including their headings)
-    let mut pages: Vec<&Page> = site_tree
-        .pages
-        .values()
-        .filter(|p| p.section_route == section.route)
-        .collect();
-    pages.sort_by_key(|p| p.weight);
-    let section_pages: Vec<Value> = pages
-        .into_iter()
-        .map(|p| {
-            use facet_value::DestructuredRef;
-            let mut page_map = VObject::new();
-            page_map.insert(VString::from("title"), Value::from(p.title.as_str()));
-            page_map.insert(
-                VString::from("permalink"),
-                Value::from(make_permalink(base_url, p.route.as_str()).as_str()),
-            );
-            page_map.insert(VString::from("path"), Value::from(p.route.as_str()));
-            page_map.insert(VString::from("weight"), Value::from(p.weight as i64));
-            page_map.insert(VString::from("toc"), headings_to_value(&p.headings));
-            // Extract description from extra.description for Zola compatibility
-            if let DestructuredRef::Object(obj) = p.extra.destructure_ref() {
-                if let Some(desc) = obj.get("description") {
-                    page_map.insert(VString::from("description"), desc.clone());
-                }
-            }
-            page_map.insert(VString::from("extra"), p.extra.clone());
-            page_map.into()
-        })
-        .collect();
-    map.insert(VString::from("pages"), VArray::from_iter(section_pages));
+    let pages = crate::pagination::listed_pages(&site_tree.pages, &section.route);
+    map.insert(
+        VString::from("pages"),
+        VArray::from_iter(section_page_values(&pages, base_url)),
+    );
 
     // Mounted sources (e.g. `/styx`, `/picante`) are separate sites that happen
     // to live under the aggregate root; they must NOT appear in the primary's
@@ -1084,7 +1366,8 @@ pub fn section_to_value(section: &Section, site_tree: &SiteTree, base_url: &str)
         })
         .unwrap_or_default();
 
-    // Add subsections (full objects, sorted by weight)
+    // Add subsections (full objects, sorted by weight). Other languages'
+    // sections, their homes included, are not children even under the root.
     let mut child_sections: Vec<&Section> = site_tree
         .sections
         .values()
@@ -1098,6 +1381,7 @@ pub fn section_to_value(section: &Section, site_tree: &SiteTree, base_url: &str)
                     .count()
                     == 0
                 && !mount_roots.contains(s.route.as_str())
+                && s.lang == section.lang
         })
         .collect();
     child_sections.sort_by_key(|s| s.weight);
@@ -1112,6 +1396,98 @@ pub fn section_to_value(section: &Section, site_tree: &SiteTree, base_url: &str)
     map.into()
 }
 
+/// Listing entries for a section's pages (`section.pages`, `paginator.pages`).
+fn section_page_values(pages: &[&Page], base_url: &str) -> Vec<Value> {
+    pages
+        .iter()
+        .map(|p| {
+            let mut page_map = VObject::new();
+            page_map.insert(VString::from("title"), Value::from(p.title.as_str()));
+            page_map.insert(
+                VString::from("permalink"),
+                Value::from(make_permalink(base_url, p.route.as_str()).as_str()),
+            );
+            page_map.insert(VString::from("path"), Value::from(p.route.as_str()));
+            page_map.insert(VString::from("weight"), Value::from(p.weight as i64));
+            page_map.insert(VString::from("toc"), headings_to_value(&p.headings));
+            insert_publishing_fields(&mut page_map, p.date, p.updated, p.draft);
+            page_map.insert(
+                VString::from("taxonomies"),
+                taxonomies_to_value(&p.taxonomies),
+            );
+            // Extract description from extra.description for Zola compatibility
+            if let DestructuredRef::Object(obj) = p.extra.destructure_ref() {
+                if let Some(desc) = obj.get("description") {
+                    page_map.insert(VString::from("description"), desc.clone());
+                }
+            }
+            page_map.insert(VString::from("extra"), p.extra.clone());
+            page_map.into()
+        })
+        .collect()
+}
+
+/// The `paginator` for pager `index` (1-based) of a paginated section: its
+/// slice of `section.pages` plus navigation between pagers. Navigation links
+/// are site-relative paths, like `section.path`.
+fn paginator_to_value(
+    section: &Section,
+    index: u32,
+    site_tree: &SiteTree,
+    base_url: &str,
+) -> Value {
+    use crate::pagination::{listed_pages, pager_count, pager_route};
+
+    let paginate_by = section.paginate_by.unwrap_or(0).max(1);
+    let pages = listed_pages(&site_tree.pages, &section.route);
+    let number_pagers = pager_count(pages.len(), paginate_by);
+    let start = ((index - 1) as usize * paginate_by as usize).min(pages.len());
+    let end = (start + paginate_by as usize).min(pages.len());
+    let path = |i: u32| Value::from(pager_route(&section.route, i).as_str());
+
+    let mut map = VObject::new();
+    map.insert(
+        VString::from("paginate_by"),
+        Value::from(paginate_by as i64),
+    );
+    map.insert(VString::from("current_index"), Value::from(index as i64));
+    map.insert(
+        VString::from("number_pagers"),
+        Value::from(number_pagers as i64),
+    );
+    map.insert(
+        VString::from("total_pages"),
+        Value::from(pages.len() as i64),
+    );
+    map.insert(VString::from("first"), path(1));
+    map.insert(VString::from("last"), path(number_pagers));
+    map.insert(
+        VString::from("previous"),
+        if index > 1 {
+            path(index - 1)
+        } else {
+            Value::NULL
+        },
+    );
+    map.insert(
+        VString::from("next"),
+        if index < number_pagers {
+            path(index + 1)
+        } else {
+            Value::NULL
+        },
+    );
+    map.insert(
+        VString::from("permalink"),
+        Value::from(make_permalink(base_url, pager_route(&section.route, index).as_str()).as_str()),
+    );
+    map.insert(
+        VString::from("pages"),
+        VArray::from_iter(section_page_values(&pages[start..end], base_url)),
+    );
+    map.into()
+}
+
 /// Convert a subsection to a value (includes pages but not recursive subsections)
 fn subsection_to_value(section: &Section, site_tree: &SiteTree, base_url: &str) -> Value {
     let mut map = VObject::new();
@@ -1143,6 +1519,7 @@ fn subsection_to_value(section: &Section, site_tree: &SiteTree, base_url: &str)
                 Value::from(make_permalink(base_url, p.route.as_str()).as_str()),
             );
             page_map.insert(VString::from("weight"), Value::from(p.weight as i64));
+            insert_publishing_fields(&mut page_map, p.date, p.updated, p.draft);
             page_map.insert(VString::from("extra"), p.extra.clone());
             page_map.into()
         })
@@ -1165,6 +1542,9 @@ pub fn path_to_route(path: &str) -> Route {
     // Handle _index (with or without leading slash)
     if p.ends_with("/_index") {
         p = p[..p.len() - 7].to_string();
+    } else if p.ends_with("/index") && p.trim_start_matches('/') != "index" {
+        // Page bundle (`article/index.md`) -> its directory
+        p = p[..p.len() - 6].to_string();
     } else if p == "_index" || p == "/_index" {
         p = String::new();
     }
@@ -1185,7 +1565,7 @@ pub fn path_to_route(path: &str) -> Route {
 /// Create a permalink from base_url and route
 /// e.g., `("https://example.com", "/spec/core/")` -> `"https://example.com/spec/core/"`
 /// e.g., `("/", "/spec/core/")` -> `"/spec/core/"`
-fn make_permalink(base_url: &str, route: &str) -> String {
+pub(crate) fn make_permalink(base_url: &str, route: &str) -> String {
     if base_url == "/" {
         route.to_string()
     } else {
@@ -0,0 +1,178 @@
+//! Scheduled publishing.
+//!
+//! A page whose frontmatter `publish_at` lies in the future is embargoed: it
+//! stays out of the site tree — and so out of listings, feeds, the sitemap and
+//! search — until [`PublishClock`] reaches it. `ddc build` reads the clock
+//! once, so future-dated pages are simply not written. The server keeps the
+//! clock moving with [`spawn_clock`]; [`embargoed_sources`] only changes value
+//! when an embargo lifts (or an author edits one), so clock ticks in between
+//! invalidate nothing downstream of it.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use facet::Facet;
+use picante::PicanteResult;
+
+use crate::db::{Db, PublishClock, SourceRegistry};
+use crate::queries::parse_file;
+use crate::serve::SiteServer;
+use crate::types::SourcePath;
+
+/// Longest the server sleeps between clock updates. Releases are timed to the
+/// second; the cap only matters if the system clock jumps while it waits.
+pub const CLOCK_TICK: Duration = Duration::from_secs(60);
+
+/// A source held back until its `publish_at`.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct Embargo {
+    pub source_path: SourcePath,
+    /// Unix timestamp the source is published at
+    pub publish_at: i64,
+}
+
+/// Sources whose `publish_at` is still ahead of [`PublishClock`], soonest
+/// release first. Nothing is embargoed while the clock is unset (the LSP
+/// shows every page).
+#[picante::tracked]
+pub async fn embargoed_sources<DB: Db>(db: &DB) -> PicanteResult<Vec<Embargo>> {
+    let Some(now) = PublishClock::now(db)? else {
+        return Ok(Vec::new());
+    };
+    let sources = SourceRegistry::sources(db)?.unwrap_or_default();
+    let mut embargoed = Vec::new();
+    for source in sources.iter() {
+        if let Ok(data) = parse_file(db, *source).await?
+            && let Some(publish_at) = data.publish_at
+            && is_embargoed(publish_at, now)
+        {
+            embargoed.push(Embargo {
+                source_path: data.source_path,
+                publish_at,
+            });
+        }
+    }
+    sort_by_release(&mut embargoed);
+    Ok(embargoed)
+}
+
+fn is_embargoed(publish_at: i64, now: i64) -> bool {
+    publish_at > now
+}
+
+fn sort_by_release(embargoed: &mut [Embargo]) {
+    embargoed.sort_by(|a, b| {
+        a.publish_at
+            .cmp(&b.publish_at)
+            .then_with(|| a.source_path.cmp(&b.source_path))
+    });
+}
+
+/// Current wall-clock time as a Unix timestamp.
+pub fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// How long to wait before the next clock update: until the soonest pending
+/// release, but never longer than [`CLOCK_TICK`].
+fn next_wake(embargoed: &[Embargo], now: i64) -> Duration {
+    embargoed
+        .first()
+        .map(|e| Duration::from_secs(e.publish_at.saturating_sub(now).max(0) as u64))
+        .map_or(CLOCK_TICK, |wait| wait.min(CLOCK_TICK))
+}
+
+/// Advance the server's [`PublishClock`] until shutdown. Each update that
+/// lifts an embargo runs as a revision and live-reloads connected browsers;
+/// updates that release nothing are invisible.
+pub fn spawn_clock(server: Arc<SiteServer>) {
+    crate::spawn::spawn(async move {
+        let mut revisions = server.subscribe_revisions();
+        let mut embargoed = current_embargoes(&server).await;
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(next_wake(&embargoed, now())) => {}
+                // Content edits can add or move an embargo: re-plan the
+                // next wake-up once each revision settles.
+                changed = revisions.changed() => {
+                    if changed.is_err() {
+                        return;
+                    }
+                    let ready = revisions.borrow_and_update().status
+                        == crate::revision::RevisionStatus::Ready;
+                    if ready {
+                        embargoed = current_embargoes(&server).await;
+                    }
+                    continue;
+                }
+            }
+            if let Err(e) = PublishClock::set(&*server.db, now()) {
+                tracing::warn!(error = ?e, "publish clock: failed to advance");
+                continue;
+            }
+            let current = current_embargoes(&server).await;
+            if current == embargoed {
+                continue;
+            }
+            let token = server.begin_revision("scheduled publish");
+            server.trigger_reload().await;
+            server.end_revision(token);
+            embargoed = current;
+        }
+    });
+}
+
+async fn current_embargoes(server: &Arc<SiteServer>) -> Vec<Embargo> {
+    crate::db::TASK_DB
+        .scope(server.db.clone(), embargoed_sources(&*server.db))
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = ?e, "publish clock: failed to read embargoes");
+            Vec::new()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn embargo(path: &str, publish_at: i64) -> Embargo {
+        Embargo {
+            source_path: SourcePath::new(path.to_string()),
+            publish_at,
+        }
+    }
+
+    #[test]
+    fn pages_are_embargoed_until_their_publish_time() {
+        assert!(is_embargoed(1_000, 999));
+        assert!(!is_embargoed(1_000, 1_000));
+        assert!(!is_embargoed(1_000, 1_001));
+    }
+
+    #[test]
+    fn soonest_release_sorts_first() {
+        let mut embargoed = vec![
+            embargo("b.md", 300),
+            embargo("c.md", 100),
+            embargo("a.md", 300),
+        ];
+        sort_by_release(&mut embargoed);
+        let order: Vec<&str> = embargoed.iter().map(|e| e.source_path.as_str()).collect();
+        assert_eq!(order, ["c.md", "a.md", "b.md"]);
+    }
+
+    #[test]
+    fn clock_wakes_at_next_release_or_tick() {
+        assert_eq!(next_wake(&[], 0), CLOCK_TICK);
+        assert_eq!(
+            next_wake(&[embargo("a.md", 105)], 100),
+            Duration::from_secs(5)
+        );
+        assert_eq!(next_wake(&[embargo("a.md", 100_000)], 100), CLOCK_TICK);
+        assert_eq!(next_wake(&[embargo("a.md", 90)], 100), Duration::ZERO);
+    }
+}
@@ -13,10 +13,13 @@
 //!   and fragments built by the search indexer from the rendered HTML. These are
 //!   content-derived; the function is a tracked query that re-runs only when
 //!   page content changes. They keep stable paths and are served revalidated.
+//!   A multilingual site gets one index per language, the translations' under
+//!   their route prefix (`/fr/search/`).
 //!
 //! Both `ddc build` (via `build_site`) and `ddc serve` (via `find_content`)
 //! pull from here, so the index is identical in dev and production.
 
+use std::collections::BTreeMap;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
@@ -113,19 +116,32 @@ fn sources_json() -> String {
     facet_json::to_string(&sources).unwrap_or_else(|_| "[]".to_string())
 }
 
+/// JSON array of the translation languages' route prefixes (`["/fr"]`), for
+/// the widget to load the index of the language being read.
+fn language_prefixes_json() -> String {
+    let prefixes: Vec<String> = crate::i18n::languages()
+        .into_iter()
+        .skip(1)
+        .map(|l| l.prefix)
+        .collect();
+    facet_json::to_string(&prefixes).unwrap_or_else(|_| "[]".to_string())
+}
+
 /// The `<head>` markup that activates the search widget on every page: the
-/// stylesheet, the configured source list (for current-site scoping), and the
-/// ES module driving the WASM query core, at their content-versioned URLs.
-/// `render.rs` injects this into every page.
+/// stylesheet, the configured source list (for current-site scoping) and
+/// language prefixes (for per-language indexes), and the ES module driving the
+/// WASM query core, at their content-versioned URLs. `render.rs` injects this
+/// into every page.
 // s[impl serve.inject]
 pub fn search_head_injection() -> String {
     let Some(dir) = runtime_dir() else {
         return String::new();
     };
     let sources = sources_json();
+    let languages = language_prefixes_json();
     format!(
         "<link rel=\"stylesheet\" href=\"/{dir}/search.css\">\
-         <script>window.__dodecaSources={sources};</script>\
+         <script>window.__dodecaSources={sources};window.__dodecaLanguages={languages};</script>\
          <script type=\"module\" src=\"/{dir}/search.js\"></script>"
     )
 }
@@ -145,7 +161,8 @@ fn route_to_url(route: &Route) -> String {
 }
 
 /// Build the `/search/` index files (manifest, shards, fragments) from every
-/// rendered page, via the search indexer.
+/// rendered page, via the search indexer. Each translation language gets its
+/// own index under `<prefix>/search/`, holding only its pages.
 ///
 /// Tracked: `serve_html` is memoized per route, so this shares phase-1 renders
 /// with `build_site` and only re-runs when page content changes. A missing or
@@ -163,25 +180,41 @@ pub async fn search_index_files<DB: Db>(db: &DB) -> PicanteResult<Vec<OutputFile
         Err(_) => return Ok(Vec::new()),
     };
 
-    let mut pages: Vec<SearchPage> = Vec::new();
-    for route in tree.sections.keys().chain(tree.pages.keys()) {
+    // Keyed by language prefix; the default language's index always exists.
+    let languages = crate::i18n::site_languages(db).await?;
+    let mut indexes: BTreeMap<String, Vec<SearchPage>> = BTreeMap::new();
+    indexes.entry(String::new()).or_default();
+    for route in tree
+        .sections
+        .keys()
+        .chain(tree.pages.keys())
+        .chain(tree.pagers.keys())
+    {
         // The search index is viewer-independent: render anonymously.
         if let Ok(Some(served)) = serve_html(db, route.clone(), false).await? {
-            pages.push(SearchPage {
-                url: route_to_url(route),
-                source: crate::queries::source_name_of(route.as_str()),
-                html: served.html,
-            });
+            let prefix = crate::i18n::language_of_route(route.as_str(), &languages)
+                .map_or("", |lang| lang.prefix.as_str());
+            indexes
+                .entry(prefix.to_string())
+                .or_default()
+                .push(SearchPage {
+                    url: route_to_url(route),
+                    source: crate::queries::source_name_of(route.as_str()),
+                    html: served.html,
+                });
         }
     }
 
-    let files = match crate::cells::build_search_index(pages).await {
-        Ok(files) => files,
-        Err(e) => {
-            tracing::warn!(error = %e, "search index unavailable; serving empty index");
-            return Ok(Vec::new());
+    let mut files = Vec::new();
+    for (prefix, pages) in indexes {
+        match crate::cells::build_search_index(pages, format!("{prefix}/search")).await {
+            Ok(index) => files.extend(index),
+            Err(e) => {
+                tracing::warn!(error = %e, "search index unavailable; serving empty index");
+                return Ok(Vec::new());
+            }
         }
-    };
+    }
 
     Ok(files
         .into_iter()
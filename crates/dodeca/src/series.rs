@@ -0,0 +1,154 @@
+//! Series: ordered runs of pages that need not share a section.
+//!
+//! A page joins a series with a `[series]` table (`name = "Async Rust"`,
+//! `part = 2`). `build_tree` gathers the members of each series into
+//! [`SiteTree::series`] ordered by part, which templates see as `page.series`
+//! and the authoring route graph as prev/next edges.
+
+use std::collections::BTreeMap;
+
+use facet_value::{DestructuredRef, Value};
+
+use crate::cells::MarkdownParseError;
+use crate::db::{Page, Series, SeriesMembership, SiteTree};
+use crate::types::Route;
+
+/// Read the frontmatter `[series]` table. A bare string is taken as the name
+/// of a series the page is part 1 of.
+pub fn frontmatter_series(value: &Value) -> Result<Option<SeriesMembership>, MarkdownParseError> {
+    let invalid = |detail: &str| MarkdownParseError {
+        message: format!("invalid frontmatter series: {detail}"),
+    };
+    match value.destructure_ref() {
+        DestructuredRef::Null => Ok(None),
+        DestructuredRef::String(name) => Ok(Some(SeriesMembership {
+            name: name.as_str().to_string(),
+            part: 1,
+        })),
+        DestructuredRef::Object(obj) => {
+            let name = obj
+                .get("name")
+                .and_then(|v| v.as_string())
+                .map(|s| s.as_str().trim().to_string())
+                .filter(|s| !s.is_empty())
+                .ok_or_else(|| invalid("expected a `name` string"))?;
+            let part = match obj.get("part") {
+                None => 1,
+                Some(part) => part
+                    .as_number()
+                    .and_then(|n| n.to_i64())
+                    .and_then(|n| u32::try_from(n).ok())
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| invalid("expected `part` to be a positive integer"))?,
+            };
+            Ok(Some(SeriesMembership { name, part }))
+        }
+        _ => Err(invalid("expected a [series] table with `name` and `part`")),
+    }
+}
+
+/// Group pages by series, each ordered by part (then route, so duplicate part
+/// numbers still give a stable order).
+pub fn collect(pages: &BTreeMap<Route, Page>) -> BTreeMap<String, Series> {
+    let mut members: BTreeMap<String, Vec<(u32, &Route)>> = BTreeMap::new();
+    for page in pages.values() {
+        if let Some(membership) = &page.series {
+            members
+                .entry(membership.name.clone())
+                .or_default()
+                .push((membership.part, &page.route));
+        }
+    }
+    members
+        .into_iter()
+        .map(|(name, mut parts)| {
+            parts.sort();
+            let series = Series {
+                name: name.clone(),
+                pages: parts.into_iter().map(|(_, route)| route.clone()).collect(),
+            };
+            (name, series)
+        })
+        .collect()
+}
+
+/// The series `page` belongs to and its 0-based position there.
+pub fn position<'a>(tree: &'a SiteTree, page: &Page) -> Option<(&'a Series, usize)> {
+    let series = tree.series.get(&page.series.as_ref()?.name)?;
+    let index = series.pages.iter().position(|r| *r == page.route)?;
+    Some((series, index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{HtmlBody, Title};
+    use facet_value::{VObject, VString};
+
+    fn page(route: &str, series: Option<(&str, u32)>) -> Page {
+        Page {
+            route: Route::new(route.to_string()),
+            title: Title::new(route.to_string()),
+            weight: 0,
+            body_html: HtmlBody::from_static(""),
+            section_route: Route::root(),
+            headings: Vec::new(),
+            rules: Vec::new(),
+            source_map: Default::default(),
+            head_injections: Vec::new(),
+            last_updated: 0,
+            date: None,
+            updated: None,
+            draft: false,
+            sitemap: Default::default(),
+            series: series.map(|(name, part)| SeriesMembership {
+                name: name.to_string(),
+                part,
+            }),
+            lang: None,
+            taxonomies: BTreeMap::new(),
+            extra: Value::default(),
+            template: None,
+        }
+    }
+
+    #[test]
+    fn frontmatter_series_reads_name_and_part() {
+        let mut table = VObject::new();
+        table.insert(VString::from("name"), Value::from("Async Rust"));
+        table.insert(VString::from("part"), Value::from(3i64));
+        let membership = frontmatter_series(&Value::from(table)).unwrap().unwrap();
+        assert_eq!(membership.name, "Async Rust");
+        assert_eq!(membership.part, 3);
+
+        assert_eq!(frontmatter_series(&Value::default()).unwrap(), None);
+
+        let mut bad = VObject::new();
+        bad.insert(VString::from("name"), Value::from("Async Rust"));
+        bad.insert(VString::from("part"), Value::from(0i64));
+        assert!(frontmatter_series(&Value::from(bad)).is_err());
+        assert!(frontmatter_series(&Value::from(VObject::new())).is_err());
+    }
+
+    #[test]
+    fn collect_orders_parts_across_sections() {
+        let pages: BTreeMap<Route, Page> = [
+            page("/blog/finale", Some(("Async Rust", 3))),
+            page("/notes/intro", Some(("Async Rust", 1))),
+            page("/blog/middle", Some(("Async Rust", 2))),
+            page("/blog/other", None),
+        ]
+        .into_iter()
+        .map(|p| (p.route.clone(), p))
+        .collect();
+
+        let series = collect(&pages);
+        assert_eq!(series.len(), 1);
+        let routes: Vec<&str> = series["Async Rust"]
+            .pages
+            .iter()
+            .map(|r| r.as_str())
+            .collect();
+        assert_eq!(routes, ["/notes/intro", "/blog/middle", "/blog/finale"]);
+    }
+}
@@ -4,7 +4,7 @@
 //! This enables instant incremental rebuilds with zero disk I/O.
 
 /// Picante cache version - bump this when making incompatible changes to picante inputs/queries
-pub const PICANTE_CACHE_VERSION: u32 = 6;
+pub const PICANTE_CACHE_VERSION: u32 = 18;
 
 use camino::{Utf8Component, Utf8Path, Utf8PathBuf};
 use eyre::{Result, bail, eyre};
@@ -16,9 +16,9 @@ use tokio::process::Command;
 use tokio::sync::{broadcast, watch};
 
 use crate::db::{
-    DataFile, DataRegistry, Database, DatabaseSnapshot, MarkdownRenderSettings, SassFile,
-    SassRegistry, SourceFile, SourceRegistry, StaticFile, StaticRegistry, TemplateFile,
-    TemplateRegistry,
+    DataFile, DataRegistry, Database, DatabaseSnapshot, MarkdownRenderSettings, PublishClock,
+    PublishSettings, SassFile, SassRegistry, SourceFile, SourceRegistry, StaticFile,
+    StaticRegistry, TemplateFile, TemplateRegistry,
 };
 use crate::image::{InputFormat, OutputFormat, add_width_suffix};
 use crate::queries::{
@@ -344,6 +344,10 @@ impl SiteServer {
             render_options.render_notes,
         )
         .expect("failed to initialize markdown render settings");
+        PublishSettings::set(&*db, render_options.dev_mode)
+            .expect("failed to initialize publish settings");
+        PublishClock::set(&*db, crate::schedule::now())
+            .expect("failed to initialize publish clock");
 
         Self {
             db,
@@ -1119,6 +1123,11 @@ impl SiteServer {
         }
     }
 
+    /// Follow revision state changes (startup, file batches, reloads).
+    pub fn subscribe_revisions(&self) -> watch::Receiver<crate::revision::RevisionState> {
+        self.revision_tx.subscribe()
+    }
+
     /// Get the current revision generation
     pub fn current_generation(&self) -> u64 {
         self.revision_tx.borrow().generation
@@ -1390,6 +1399,8 @@ impl SiteServer {
             self.render_options.source_maps,
             self.render_options.render_notes,
         )?;
+        PublishSettings::set(&*self.db, self.render_options.dev_mode)?;
+        PublishClock::set(&*self.db, crate::schedule::now())?;
         Ok(())
     }
 
@@ -1509,9 +1520,7 @@ impl SiteServer {
                 let known_routes: Option<HashSet<String>> = match build_tree(&snapshot).await {
                     Ok(Ok(site_tree)) => Some(
                         site_tree
-                            .sections
-                            .keys()
-                            .chain(site_tree.pages.keys())
+                            .link_targets()
                             .map(|r| r.as_str().to_string())
                             .collect(),
                     ),
@@ -1974,9 +1983,7 @@ impl SiteServer {
             match build_tree(&snapshot).await {
                 Ok(Ok(site_tree)) => {
                     let routes: HashSet<String> = site_tree
-                        .sections
-                        .keys()
-                        .chain(site_tree.pages.keys())
+                        .link_targets()
                         .map(|r| r.as_str().to_string())
                         .collect();
                     Some(routes)
@@ -2051,6 +2058,14 @@ impl SiteServer {
         };
 
         let route = Route::new(route_path.clone());
+
+        // Old routes (page aliases, `site.redirects`) answer with a 301.
+        if let Ok(Ok(tree)) = build_tree(&snapshot).await
+            && let Some(location) = tree.redirects.get(&route)
+        {
+            return Some(ServeContent::Redirect(location.clone()));
+        }
+
         tracing::debug!(route = %route.as_str(), can_edit, "find_content: calling serve_html");
         let serve_html_result = serve_html(&snapshot, route, can_edit).await;
         tracing::debug!(route = %route_path, has_result = serve_html_result.is_ok(), "find_content: serve_html returned");
@@ -2333,9 +2348,11 @@ impl SiteServer {
             }
         }
 
-        // 4. Search assets, served under `/search/`.
+        // 4. Search assets, served under `/search/` (and a translation
+        // language's index under `<prefix>/search/`).
         if let Some(rel) = path.strip_prefix('/')
-            && rel.starts_with("search/")
+            && crate::i18n::strip_language_prefix(path, &crate::i18n::languages())
+                .starts_with("/search/")
         {
             // Runtime assets (wasm core, loader, UI, CSS) live under a
             // content-versioned directory — safe to cache immutably.
@@ -2359,6 +2376,38 @@ impl SiteServer {
             }
         }
 
+        // 5. Section feeds (`blog/atom.xml`, `blog/rss.xml`, `blog/feed.json`),
+        // regenerated on every edit just like the search index.
+        if let Some(rel) = path.strip_prefix('/')
+            && let Some(format) = crate::feeds::format_for_path(rel)
+            && let Ok(files) = crate::feeds::feed_files(&snapshot).await
+        {
+            for file in files {
+                if let crate::db::OutputFile::Static { path: p, content } = file
+                    && p.as_str() == rel
+                {
+                    return Some(ServeContent::StaticNoCache(content, format.mime()));
+                }
+            }
+        }
+
+        // 6. `sitemap.xml` (and its parts when split) and `robots.txt`.
+        if let Some(rel) = path.strip_prefix('/')
+            && crate::sitemap::is_sitemap_path(rel)
+            && let Ok(files) = crate::sitemap::sitemap_files(&snapshot).await
+        {
+            for file in files {
+                if let crate::db::OutputFile::Static { path: p, content } = file
+                    && p.as_str() == rel
+                {
+                    return Some(ServeContent::StaticNoCache(
+                        content,
+                        mime_from_extension(path),
+                    ));
+                }
+            }
+        }
+
         None
     }
 
@@ -2717,6 +2766,11 @@ impl SiteServer {
                 mime: mime.to_string(),
                 generation,
             },
+            Some(ServeContent::Redirect(location)) => RpcServeContent::Redirect {
+                location,
+                permanent: true,
+                generation,
+            },
             None => {
                 // Static asset misses should return a direct 404; route suggestions are for pages.
                 let similar = if should_suggest_routes_for_404(path) {
@@ -3449,6 +3503,8 @@ enum ServeContent {
     Static(Vec<u8>, &'static str),
     /// Static file served at original path (no caching, for favicon etc.)
     StaticNoCache(Vec<u8>, &'static str),
+    /// Permanent redirect (page aliases, `site.redirects`)
+    Redirect(String),
 }
 
 /// Compute a short hash for cache busting
@@ -0,0 +1,339 @@
+//! `sitemap.xml` and `robots.txt`.
+//!
+//! The sitemap lists every rendered route (sections, pages, pagers and
+//! taxonomy pages) minus those whose frontmatter says `sitemap = false`. Past
+//! [`MAX_URLS_PER_SITEMAP`] URLs it is split into `sitemap1.xml`,
+//! `sitemap2.xml`, … and `sitemap.xml` becomes a sitemap index.
+
+use chrono::{DateTime, SecondsFormat};
+use facet::Facet;
+use picante::PicanteResult;
+
+use crate::cells::MarkdownParseError;
+use crate::db::{Db, OutputFile, SiteTree, StaticRegistry};
+use crate::queries::build_tree;
+use crate::types::{Route, StaticPath};
+
+/// Most URLs a single sitemap file may list (sitemaps.org protocol limit).
+pub const MAX_URLS_PER_SITEMAP: usize = 50_000;
+
+const SITEMAP_FILE: &str = "sitemap.xml";
+const ROBOTS_FILE: &str = "robots.txt";
+
+/// Frontmatter sitemap settings of a page or section.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Facet)]
+pub struct SitemapSettings {
+    /// `false` when frontmatter says `sitemap = false`
+    pub include: bool,
+    /// `<priority>` as written to the sitemap (e.g. `0.8`)
+    pub priority: Option<String>,
+}
+
+impl Default for SitemapSettings {
+    fn default() -> Self {
+        SitemapSettings {
+            include: true,
+            priority: None,
+        }
+    }
+}
+
+/// Read the frontmatter `sitemap` / `sitemap_priority` fields.
+pub fn parse_sitemap_settings(
+    include: Option<bool>,
+    priority: Option<f64>,
+) -> Result<SitemapSettings, MarkdownParseError> {
+    let priority = match priority {
+        Some(p) if !(0.0..=1.0).contains(&p) => {
+            return Err(MarkdownParseError {
+                message: format!(
+                    "invalid frontmatter sitemap_priority '{p}': expected a number from 0.0 to 1.0"
+                ),
+            });
+        }
+        Some(p) => Some(p.to_string()),
+        None => None,
+    };
+    Ok(SitemapSettings {
+        include: include.unwrap_or(true),
+        priority,
+    })
+}
+
+/// Whether a request path (without leading slash) names a file this module
+/// generates. Lets `ddc serve` skip sitemap generation for everything else.
+pub fn is_sitemap_path(rel: &str) -> bool {
+    rel == SITEMAP_FILE
+        || rel == ROBOTS_FILE
+        || rel
+            .strip_prefix("sitemap")
+            .and_then(|rest| rest.strip_suffix(".xml"))
+            .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// `sitemap.xml` (plus numbered sitemaps when split) and, unless the site
+/// ships its own as a stable asset, `robots.txt`.
+#[picante::tracked]
+pub async fn sitemap_files<DB: Db>(db: &DB) -> PicanteResult<Vec<OutputFile>> {
+    let tree = match build_tree(db).await? {
+        Ok(tree) => tree,
+        Err(_) => return Ok(Vec::new()),
+    };
+    let config = crate::db::ConfigRegistry::config(db)?;
+    let base_url = config
+        .as_ref()
+        .map_or_else(|| "/".to_string(), |c| c.base_url.clone());
+
+    let mut files = Vec::new();
+    for (path, content) in render_sitemaps(&entries(&tree), &base_url) {
+        files.push(OutputFile::Static {
+            path: StaticPath::new(path),
+            content: content.into_bytes(),
+        });
+    }
+
+    let ships_robots = config.is_some_and(|c| c.stable_assets.iter().any(|a| a == ROBOTS_FILE))
+        && has_static_file(db, ROBOTS_FILE)?;
+    if !ships_robots {
+        files.push(OutputFile::Static {
+            path: StaticPath::new(ROBOTS_FILE.to_string()),
+            content: robots_txt(&base_url).into_bytes(),
+        });
+    }
+
+    Ok(files)
+}
+
+fn has_static_file<DB: Db>(db: &DB, path: &str) -> PicanteResult<bool> {
+    for file in StaticRegistry::files(db)?.unwrap_or_default().iter() {
+        if file.path(db)?.as_str() == path {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// One `<url>` of the sitemap.
+#[derive(Debug, PartialEq)]
+struct SitemapEntry<'a> {
+    route: &'a Route,
+    /// Unix seconds
+    lastmod: Option<i64>,
+    priority: Option<&'a str>,
+}
+
+/// Sitemap entries for every rendered route, in `html_routes` order.
+fn entries(tree: &SiteTree) -> Vec<SitemapEntry<'_>> {
+    let mut entries = Vec::new();
+    for section in tree.sections.values() {
+        if section.sitemap.include {
+            entries.push(SitemapEntry {
+                route: &section.route,
+                lastmod: Some(section.last_updated),
+                priority: section.sitemap.priority.as_deref(),
+            });
+        }
+    }
+    for page in tree.pages.values() {
+        if page.sitemap.include {
+            entries.push(SitemapEntry {
+                route: &page.route,
+                lastmod: Some(page.last_updated),
+                priority: page.sitemap.priority.as_deref(),
+            });
+        }
+    }
+    for pager in tree.pagers.values() {
+        // A pager is its section's listing again: same settings.
+        if let Some(section) = tree.sections.get(&pager.section)
+            && section.sitemap.include
+        {
+            entries.push(SitemapEntry {
+                route: &pager.route,
+                lastmod: Some(section.last_updated),
+                priority: section.sitemap.priority.as_deref(),
+            });
+        }
+    }
+    // Taxonomy pages change when a page listed on them does.
+    for route in tree.taxonomy_routes() {
+        let listed: Vec<&Route> = tree
+            .taxonomies
+            .values()
+            .flat_map(|taxonomy| {
+                let whole = taxonomy.route == *route;
+                taxonomy
+                    .terms
+                    .values()
+                    .filter(move |term| whole || term.route == *route)
+                    .flat_map(|term| term.pages.iter())
+            })
+            .collect();
+        entries.push(SitemapEntry {
+            route,
+            lastmod: listed
+                .into_iter()
+                .filter_map(|route| tree.pages.get(route))
+                .map(|page| page.last_updated)
+                .max(),
+            priority: None,
+        });
+    }
+    entries
+}
+
+/// `(path, content)` of every sitemap file: a single `sitemap.xml`, or a
+/// sitemap index plus `sitemap1.xml`, `sitemap2.xml`, … past the URL limit.
+fn render_sitemaps(entries: &[SitemapEntry<'_>], base_url: &str) -> Vec<(String, String)> {
+    if entries.len() <= MAX_URLS_PER_SITEMAP {
+        return vec![(SITEMAP_FILE.to_string(), urlset(entries, base_url))];
+    }
+
+    let mut files = Vec::new();
+    let mut index = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for (i, chunk) in entries.chunks(MAX_URLS_PER_SITEMAP).enumerate() {
+        let path = format!("sitemap{}.xml", i + 1);
+        index.push_str(&format!(
+            "  <sitemap>\n    <loc>{}</loc>\n",
+            escape(&permalink(base_url, &format!("/{path}")))
+        ));
+        if let Some(lastmod) = chunk.iter().filter_map(|e| e.lastmod).max() {
+            index.push_str(&format!(
+                "    <lastmod>{}</lastmod>\n",
+                w3c_datetime(lastmod)
+            ));
+        }
+        index.push_str("  </sitemap>\n");
+        files.push((path, urlset(chunk, base_url)));
+    }
+    index.push_str("</sitemapindex>\n");
+    files.insert(0, (SITEMAP_FILE.to_string(), index));
+    files
+}
+
+fn urlset(entries: &[SitemapEntry<'_>], base_url: &str) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for entry in entries {
+        out.push_str(&format!(
+            "  <url>\n    <loc>{}</loc>\n",
+            escape(&permalink(base_url, entry.route.as_str()))
+        ));
+        if let Some(lastmod) = entry.lastmod {
+            out.push_str(&format!(
+                "    <lastmod>{}</lastmod>\n",
+                w3c_datetime(lastmod)
+            ));
+        }
+        if let Some(priority) = entry.priority {
+            out.push_str(&format!("    <priority>{priority}</priority>\n"));
+        }
+        out.push_str("  </url>\n");
+    }
+    out.push_str("</urlset>\n");
+    out
+}
+
+fn robots_txt(base_url: &str) -> String {
+    format!(
+        "User-agent: *\nDisallow:\n\nSitemap: {}\n",
+        permalink(base_url, &format!("/{SITEMAP_FILE}"))
+    )
+}
+
+fn permalink(base_url: &str, route: &str) -> String {
+    format!("{}{route}", base_url.trim_end_matches('/'))
+}
+
+fn w3c_datetime(secs: i64) -> String {
+    DateTime::from_timestamp(secs, 0)
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes(n: usize) -> Vec<Route> {
+        (0..n).map(|i| Route::new(format!("/p{i}"))).collect()
+    }
+
+    fn entries_for(routes: &[Route]) -> Vec<SitemapEntry<'_>> {
+        routes
+            .iter()
+            .map(|route| SitemapEntry {
+                route,
+                lastmod: Some(1_714_521_600),
+                priority: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn small_sites_get_a_single_urlset() {
+        let routes = [Route::root(), Route::new("/a&b".to_string())];
+        let mut entries = entries_for(&routes);
+        entries[1].priority = Some("0.8");
+
+        let files = render_sitemaps(&entries, "https://example.com/");
+        assert_eq!(files.len(), 1);
+        let (path, xml) = &files[0];
+        assert_eq!(path, "sitemap.xml");
+        assert!(xml.contains("<loc>https://example.com/</loc>"));
+        assert!(xml.contains("<loc>https://example.com/a&amp;b</loc>"));
+        assert!(xml.contains("<lastmod>2024-05-01T00:00:00Z</lastmod>"));
+        assert!(xml.contains("<priority>0.8</priority>"));
+    }
+
+    #[test]
+    fn large_sites_get_a_sitemap_index() {
+        let routes = routes(MAX_URLS_PER_SITEMAP + 1);
+        let files = render_sitemaps(&entries_for(&routes), "https://example.com");
+
+        let paths: Vec<&str> = files.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(paths, ["sitemap.xml", "sitemap1.xml", "sitemap2.xml"]);
+        assert!(files[0].1.contains("<sitemapindex"));
+        assert!(
+            files[0]
+                .1
+                .contains("<loc>https://example.com/sitemap2.xml</loc>")
+        );
+        assert_eq!(files[2].1.matches("<url>").count(), 1);
+    }
+
+    #[test]
+    fn sitemap_priority_must_be_a_fraction() {
+        let settings = parse_sitemap_settings(None, Some(0.8)).unwrap();
+        assert!(settings.include);
+        assert_eq!(settings.priority.as_deref(), Some("0.8"));
+        assert!(parse_sitemap_settings(Some(false), None).is_ok_and(|s| !s.include));
+        assert!(parse_sitemap_settings(None, Some(1.5)).is_err());
+    }
+
+    #[test]
+    fn sitemap_paths() {
+        assert!(is_sitemap_path("sitemap.xml"));
+        assert!(is_sitemap_path("sitemap12.xml"));
+        assert!(is_sitemap_path("robots.txt"));
+        assert!(!is_sitemap_path("sitemapx.xml"));
+        assert!(!is_sitemap_path("blog/sitemap.xml"));
+    }
+
+    #[test]
+    fn robots_points_at_the_sitemap() {
+        assert_eq!(
+            robots_txt("https://example.com/"),
+            "User-agent: *\nDisallow:\n\nSitemap: https://example.com/sitemap.xml\n"
+        );
+    }
+}
@@ -0,0 +1,558 @@
+//! Taxonomies: site-wide groupings (`tags`, `categories`, …) declared in
+//! `site { taxonomies {...} }` and populated from page frontmatter.
+//!
+//! [`collect`] runs inside `build_tree` and gives every taxonomy an index route
+//! (`/tags`) and every term a route (`/tags/rust`). Rendering goes through
+//! narrow tracked queries: a term page reads only its own [`taxonomy_term`]
+//! listing plus [`taxonomy_render_tree`], a tree stripped of everything a tag
+//! edit touches. Retagging a page (in `[taxonomies]` or `extra.<name>`)
+//! therefore cuts off early everywhere except the term pages whose listings
+//! actually changed.
+
+use std::collections::BTreeMap;
+
+use facet::Facet;
+use facet_value::{DestructuredRef, Value};
+use picante::PicanteResult;
+
+use crate::cells::MarkdownParseError;
+use crate::config::TaxonomyDef;
+use crate::dates::newest_first;
+use crate::db::{Db, Page, ParsedData, RenderedHtml, SiteTree, Taxonomy, TaxonomyTerm};
+use crate::queries::{
+    BuildError, BuildTreeResult, RenderError, SiteError, build_tree, load_all_templates,
+    templates_for_route, title_case_slug,
+};
+use crate::types::{Route, Title};
+
+/// Everything a term page renders: the taxonomy it belongs to, the term, and
+/// the tagged pages themselves (newest first).
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct TermListing {
+    pub taxonomy_name: String,
+    pub taxonomy_title: Title,
+    pub taxonomy_route: Route,
+    pub term: TaxonomyTerm,
+    pub pages: Vec<Page>,
+}
+
+/// A generated route, resolved back to what it lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaxonomyRoute {
+    /// `/<taxonomy>`: every term of the taxonomy
+    Index { taxonomy: String },
+    /// `/<taxonomy>/<slug>`: the pages tagged with one term
+    Term { taxonomy: String, slug: String },
+}
+
+/// Read the frontmatter `[taxonomies]` table. Each entry is a list of terms; a
+/// bare string is accepted as a single term.
+pub fn frontmatter_taxonomies(
+    value: &Value,
+) -> Result<BTreeMap<String, Vec<String>>, MarkdownParseError> {
+    let mut taxonomies = BTreeMap::new();
+    match value.destructure_ref() {
+        DestructuredRef::Null => {}
+        DestructuredRef::Object(obj) => {
+            for (name, terms) in obj.iter() {
+                let terms = terms_from_value(terms).ok_or_else(|| MarkdownParseError {
+                    message: format!(
+                        "invalid frontmatter taxonomies.{}: expected a list of strings",
+                        name.as_str()
+                    ),
+                })?;
+                taxonomies.insert(name.as_str().to_string(), terms);
+            }
+        }
+        _ => {
+            return Err(MarkdownParseError {
+                message: "invalid frontmatter taxonomies: expected a [taxonomies] table"
+                    .to_string(),
+            });
+        }
+    }
+    Ok(taxonomies)
+}
+
+fn terms_from_value(value: &Value) -> Option<Vec<String>> {
+    if let Some(term) = value.as_string() {
+        return Some(vec![term.as_str().to_string()]);
+    }
+    value
+        .as_array()?
+        .iter()
+        .map(|term| term.as_string().map(|s| s.as_str().to_string()))
+        .collect()
+}
+
+/// A page's terms for each configured taxonomy. The `[taxonomies]` table wins;
+/// otherwise a string list under `extra.<name>` is picked up, so sites that
+/// kept `tags` in `[extra]` get term pages without touching their content.
+pub fn page_terms(
+    data: &ParsedData,
+    defs: &BTreeMap<String, TaxonomyDef>,
+) -> BTreeMap<String, Vec<String>> {
+    let extra = match data.extra.destructure_ref() {
+        DestructuredRef::Object(obj) => Some(obj),
+        _ => None,
+    };
+    defs.keys()
+        .filter_map(|name| {
+            let terms = data.taxonomies.get(name).cloned().or_else(|| {
+                extra
+                    .and_then(|obj| obj.get(name.as_str()))
+                    .and_then(terms_from_value)
+            })?;
+            let terms: Vec<String> = terms
+                .into_iter()
+                .map(|term| term.trim().to_string())
+                .filter(|term| !marq::slugify(term).is_empty())
+                .collect();
+            (!terms.is_empty()).then(|| (name.clone(), terms))
+        })
+        .collect()
+}
+
+/// Aggregate every page's terms into the configured taxonomies. Terms are keyed
+/// by slug, so `Rust` and `rust` are one term, named after its first use in
+/// route order.
+pub fn collect(
+    pages: &BTreeMap<Route, Page>,
+    defs: &BTreeMap<String, TaxonomyDef>,
+) -> BTreeMap<String, Taxonomy> {
+    let mut taxonomies: BTreeMap<String, Taxonomy> = defs
+        .iter()
+        .map(|(name, def)| {
+            let title = def.title.clone().unwrap_or_else(|| title_case_slug(name));
+            (
+                name.clone(),
+                Taxonomy {
+                    name: name.clone(),
+                    title: Title::new(title),
+                    route: Route::new(format!("/{}", name.trim_matches('/'))),
+                    terms: BTreeMap::new(),
+                },
+            )
+        })
+        .collect();
+
+    for page in pages.values() {
+        for (name, terms) in &page.taxonomies {
+            let Some(taxonomy) = taxonomies.get_mut(name) else {
+                continue;
+            };
+            for term in terms {
+                let slug = marq::slugify(term);
+                let entry = taxonomy
+                    .terms
+                    .entry(slug.clone())
+                    .or_insert_with(|| TaxonomyTerm {
+                        name: term.clone(),
+                        route: Route::new(format!("{}/{}", taxonomy.route.as_str(), slug)),
+                        slug,
+                        pages: Vec::new(),
+                    });
+                if !entry.pages.contains(&page.route) {
+                    entry.pages.push(page.route.clone());
+                }
+            }
+        }
+    }
+
+    for taxonomy in taxonomies.values_mut() {
+        for term in taxonomy.terms.values_mut() {
+            term.pages
+                .sort_by(|a, b| newest_first(&pages[a], &pages[b]));
+        }
+    }
+
+    taxonomies
+}
+
+/// Resolve a route to the taxonomy index or term page it names, if any.
+pub fn resolve_route(tree: &SiteTree, route: &Route) -> Option<TaxonomyRoute> {
+    tree.taxonomies.values().find_map(|taxonomy| {
+        if taxonomy.route == *route {
+            return Some(TaxonomyRoute::Index {
+                taxonomy: taxonomy.name.clone(),
+            });
+        }
+        let slug = route
+            .as_str()
+            .strip_prefix(taxonomy.route.as_str())?
+            .strip_prefix('/')?;
+        taxonomy
+            .terms
+            .contains_key(slug)
+            .then(|| TaxonomyRoute::Term {
+                taxonomy: taxonomy.name.clone(),
+                slug: slug.to_string(),
+            })
+    })
+}
+
+/// The site tree templates see on taxonomy pages, see [`strip_for_render`].
+#[picante::tracked]
+pub async fn taxonomy_render_tree<DB: Db>(db: &DB) -> PicanteResult<BuildTreeResult> {
+    Ok(build_tree(db).await?.map(strip_for_render))
+}
+
+/// `build_tree`'s tree minus everything editing a page's tags touches: the
+/// aggregated taxonomies, each page's terms (including ones kept in
+/// `extra.<name>`), its source map (byte offsets shift when the frontmatter
+/// grows) and `last_updated` (the file's mtime). Term listings keep all of
+/// these, so a tag edit leaves this tree unchanged; only the listings of the
+/// terms it adds the page to or removes it from differ.
+pub fn strip_for_render(mut tree: SiteTree) -> SiteTree {
+    let names: Vec<String> = std::mem::take(&mut tree.taxonomies).into_keys().collect();
+    for page in tree.pages.values_mut() {
+        page.taxonomies.clear();
+        page.source_map = Default::default();
+        page.last_updated = 0;
+        if let Some(extra) = page.extra.as_object_mut() {
+            for name in &names {
+                extra.remove(name);
+            }
+        }
+    }
+    tree
+}
+
+/// One taxonomy with its terms, for the index page.
+#[picante::tracked]
+pub async fn taxonomy_index<DB: Db>(db: &DB, taxonomy: String) -> PicanteResult<Option<Taxonomy>> {
+    let Ok(tree) = build_tree(db).await? else {
+        return Ok(None);
+    };
+    Ok(tree.taxonomies.get(&taxonomy).cloned())
+}
+
+/// One term with its tagged pages, for the term page.
+#[picante::tracked]
+pub async fn taxonomy_term<DB: Db>(
+    db: &DB,
+    taxonomy: String,
+    slug: String,
+) -> PicanteResult<Option<TermListing>> {
+    let Ok(tree) = build_tree(db).await? else {
+        return Ok(None);
+    };
+    let Some(tax) = tree.taxonomies.get(&taxonomy) else {
+        return Ok(None);
+    };
+    let Some(term) = tax.terms.get(&slug) else {
+        return Ok(None);
+    };
+    Ok(Some(TermListing {
+        taxonomy_name: tax.name.clone(),
+        taxonomy_title: tax.title.clone(),
+        taxonomy_route: tax.route.clone(),
+        term: term.clone(),
+        pages: term
+            .pages
+            .iter()
+            .filter_map(|route| tree.pages.get(route).cloned())
+            .collect(),
+    }))
+}
+
+/// Render a taxonomy index page with `taxonomy.html`.
+#[picante::tracked]
+#[tracing::instrument(skip_all, name = "render_taxonomy", fields(taxonomy = %taxonomy))]
+pub async fn render_taxonomy<DB: Db>(
+    db: &DB,
+    taxonomy: String,
+    can_edit: bool,
+) -> PicanteResult<Result<RenderedHtml, SiteError>> {
+    use crate::render::{Renderable, try_render_template};
+    use crate::shortcode::resolve_shortcodes;
+
+    let site_tree = match taxonomy_render_tree(db).await? {
+        Ok(tree) => tree,
+        Err(errors) => return Ok(Err(BuildError { errors }.into())),
+    };
+    let index = taxonomy_index(db, taxonomy)
+        .await?
+        .expect("Taxonomy not found");
+
+    let all_templates = load_all_templates(db).await?;
+    let templates = templates_for_route(all_templates.clone(), index.route.as_str());
+
+    match try_render_template(
+        Renderable::Taxonomy(&index),
+        &site_tree,
+        templates,
+        can_edit,
+    )
+    .await
+    {
+        Ok(html) => {
+            let html = resolve_shortcodes(html, &all_templates, &site_tree).await;
+            Ok(Ok(RenderedHtml(html)))
+        }
+        Err(error) => Ok(Err(RenderError {
+            route: index.route.clone(),
+            error,
+        }
+        .into())),
+    }
+}
+
+/// Render a term page with `taxonomy_term.html`.
+#[picante::tracked]
+#[tracing::instrument(skip_all, name = "render_taxonomy_term", fields(taxonomy = %taxonomy, slug = %slug))]
+pub async fn render_taxonomy_term<DB: Db>(
+    db: &DB,
+    taxonomy: String,
+    slug: String,
+    can_edit: bool,
+) -> PicanteResult<Result<RenderedHtml, SiteError>> {
+    use crate::render::{Renderable, try_render_template};
+    use crate::shortcode::resolve_shortcodes;
+
+    let site_tree = match taxonomy_render_tree(db).await? {
+        Ok(tree) => tree,
+        Err(errors) => return Ok(Err(BuildError { errors }.into())),
+    };
+    let listing = taxonomy_term(db, taxonomy, slug)
+        .await?
+        .expect("Taxonomy term not found");
+
+    let all_templates = load_all_templates(db).await?;
+    let templates = templates_for_route(all_templates.clone(), listing.term.route.as_str());
+
+    match try_render_template(
+        Renderable::TaxonomyTerm(&listing),
+        &site_tree,
+        templates,
+        can_edit,
+    )
+    .await
+    {
+        Ok(html) => {
+            let html = resolve_shortcodes(html, &all_templates, &site_tree).await;
+            Ok(Ok(RenderedHtml(html)))
+        }
+        Err(error) => Ok(Err(RenderError {
+            route: listing.term.route.clone(),
+            error,
+        }
+        .into())),
+    }
+}
+
+/// Render whichever taxonomy page `route` names; `None` if it names none.
+pub async fn render_taxonomy_route<DB: Db>(
+    db: &DB,
+    tree: &SiteTree,
+    route: &Route,
+    can_edit: bool,
+) -> PicanteResult<Option<Result<RenderedHtml, SiteError>>> {
+    Ok(match resolve_route(tree, route) {
+        Some(TaxonomyRoute::Index { taxonomy }) => {
+            Some(render_taxonomy(db, taxonomy, can_edit).await?)
+        }
+        Some(TaxonomyRoute::Term { taxonomy, slug }) => {
+            Some(render_taxonomy_term(db, taxonomy, slug, can_edit).await?)
+        }
+        None => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::HtmlBody;
+    use facet_value::{VArray, VObject, VString};
+
+    fn page(route: &str, date: Option<i64>, taxonomies: &[(&str, &[&str])]) -> Page {
+        Page {
+            route: Route::new(route.to_string()),
+            title: Title::new(route.trim_start_matches('/').to_string()),
+            weight: 0,
+            body_html: HtmlBody::from_static(""),
+            section_route: Route::root(),
+            headings: Vec::new(),
+            rules: Vec::new(),
+            source_map: Default::default(),
+            head_injections: Vec::new(),
+            last_updated: 0,
+            date,
+            updated: None,
+            draft: false,
+            sitemap: Default::default(),
+            series: None,
+            lang: None,
+            taxonomies: taxonomies
+                .iter()
+                .map(|(name, terms)| {
+                    (
+                        name.to_string(),
+                        terms.iter().map(|t| t.to_string()).collect(),
+                    )
+                })
+                .collect(),
+            extra: Value::default(),
+            template: None,
+        }
+    }
+
+    fn defs(names: &[&str]) -> BTreeMap<String, TaxonomyDef> {
+        names
+            .iter()
+            .map(|name| (name.to_string(), TaxonomyDef::default()))
+            .collect()
+    }
+
+    #[test]
+    fn frontmatter_taxonomies_accepts_lists_and_single_strings() {
+        let mut table = VObject::new();
+        table.insert(
+            VString::from("tags"),
+            Value::from(VArray::from_iter([
+                Value::from("rust"),
+                Value::from("async"),
+            ])),
+        );
+        table.insert(VString::from("categories"), Value::from("notes"));
+        let parsed = frontmatter_taxonomies(&Value::from(table)).unwrap();
+        assert_eq!(parsed["tags"], vec!["rust", "async"]);
+        assert_eq!(parsed["categories"], vec!["notes"]);
+
+        assert!(
+            frontmatter_taxonomies(&Value::default())
+                .unwrap()
+                .is_empty()
+        );
+
+        let mut bad = VObject::new();
+        bad.insert(VString::from("tags"), Value::from(3i64));
+        assert!(frontmatter_taxonomies(&Value::from(bad)).is_err());
+    }
+
+    #[test]
+    fn collect_groups_terms_by_slug_newest_first() {
+        let pages: BTreeMap<Route, Page> = [
+            page("/old", Some(100), &[("tags", &["rust"])]),
+            page("/new", Some(200), &[("tags", &["Rust", "Async Rust"])]),
+            page("/other", None, &[("categories", &["notes"])]),
+        ]
+        .into_iter()
+        .map(|p| (p.route.clone(), p))
+        .collect();
+
+        let taxonomies = collect(&pages, &defs(&["tags"]));
+        assert_eq!(taxonomies.len(), 1, "undeclared taxonomies are ignored");
+
+        let tags = &taxonomies["tags"];
+        assert_eq!(tags.title.as_str(), "Tags");
+        assert_eq!(tags.route.as_str(), "/tags");
+
+        let rust = &tags.terms["rust"];
+        assert_eq!(rust.name, "Rust", "first use in route order names the term");
+        assert_eq!(rust.route.as_str(), "/tags/rust");
+        let routes: Vec<&str> = rust.pages.iter().map(|r| r.as_str()).collect();
+        assert_eq!(routes, ["/new", "/old"]);
+
+        assert_eq!(tags.terms["async-rust"].pages.len(), 1);
+    }
+
+    #[test]
+    fn resolve_route_finds_index_and_terms() {
+        let pages: BTreeMap<Route, Page> = [page("/a", None, &[("tags", &["rust"])])]
+            .into_iter()
+            .map(|p| (p.route.clone(), p))
+            .collect();
+        let tree = SiteTree {
+            taxonomies: collect(&pages, &defs(&["tags"])),
+            sections: BTreeMap::new(),
+            pages,
+            pagers: BTreeMap::new(),
+            series: BTreeMap::new(),
+            translations: BTreeMap::new(),
+            redirects: BTreeMap::new(),
+        };
+
+        assert_eq!(
+            resolve_route(&tree, &Route::new("/tags".to_string())),
+            Some(TaxonomyRoute::Index {
+                taxonomy: "tags".to_string()
+            })
+        );
+        assert_eq!(
+            resolve_route(&tree, &Route::new("/tags/rust".to_string())),
+            Some(TaxonomyRoute::Term {
+                taxonomy: "tags".to_string(),
+                slug: "rust".to_string()
+            })
+        );
+        assert_eq!(
+            resolve_route(&tree, &Route::new("/tags/go".to_string())),
+            None
+        );
+        assert_eq!(resolve_route(&tree, &Route::new("/a".to_string())), None);
+    }
+
+    fn tree(pages: Vec<Page>) -> SiteTree {
+        let pages: BTreeMap<Route, Page> =
+            pages.into_iter().map(|p| (p.route.clone(), p)).collect();
+        SiteTree {
+            taxonomies: collect(&pages, &defs(&["tags"])),
+            sections: BTreeMap::new(),
+            pagers: BTreeMap::new(),
+            series: BTreeMap::new(),
+            translations: BTreeMap::new(),
+            redirects: BTreeMap::new(),
+            pages,
+        }
+    }
+
+    fn extra_tags(terms: &[&str]) -> Value {
+        let mut extra = VObject::new();
+        extra.insert(
+            VString::from("tags"),
+            Value::from(VArray::from_iter(terms.iter().map(|t| Value::from(*t)))),
+        );
+        extra.insert(VString::from("author"), Value::from("amos"));
+        Value::from(extra)
+    }
+
+    #[test]
+    fn retagging_leaves_render_tree_and_other_terms_unchanged() {
+        let unrelated = page("/unrelated", Some(50), &[("tags", &["go"])]);
+        let before = tree(vec![
+            page("/post", Some(100), &[("tags", &["rust"])]),
+            unrelated.clone(),
+        ]);
+        // The edit also shifts the source map and bumps the mtime.
+        let mut retagged = page("/post", Some(100), &[("tags", &["rust", "async"])]);
+        retagged.last_updated = 42;
+        let after = tree(vec![retagged, unrelated]);
+
+        assert_ne!(before, after);
+        assert_eq!(
+            before.taxonomies["tags"].terms["go"],
+            after.taxonomies["tags"].terms["go"]
+        );
+        assert_eq!(strip_for_render(before), strip_for_render(after));
+    }
+
+    #[test]
+    fn retagging_extra_tags_leaves_render_tree_unchanged() {
+        let with_extra = |terms: &[&str]| {
+            let mut p = page("/legacy", None, &[("tags", terms)]);
+            p.extra = extra_tags(terms);
+            p
+        };
+        let before = strip_for_render(tree(vec![with_extra(&["python"])]));
+        let after = strip_for_render(tree(vec![with_extra(&["python", "async"])]));
+
+        assert_eq!(before, after);
+        let extra = before.pages[&Route::new("/legacy".to_string())]
+            .extra
+            .as_object()
+            .unwrap();
+        assert!(extra.get("tags").is_none());
+        assert!(extra.get("author").is_some(), "other extra keys are kept");
+    }
+}
@@ -121,6 +121,21 @@ impl Route {
     pub fn parent(&self) -> Option<Route> {
         RouteRef::from_str(self.as_str()).parent()
     }
+
+    /// Replace the last segment with a frontmatter slug
+    /// (e.g., "/blog/2024-05-01-hello" + "hello" -> "/blog/hello").
+    /// The root route has no segment to replace and is returned unchanged.
+    pub fn with_slug(&self, slug: &str) -> Route {
+        let slug = slug.trim_matches('/');
+        if slug.is_empty() || self.as_str() == "/" {
+            return self.clone();
+        }
+        match self.parent() {
+            Some(parent) if parent.as_str() == "/" => Route::new(format!("/{slug}")),
+            Some(parent) => Route::new(format!("{parent}/{slug}")),
+            None => self.clone(),
+        }
+    }
 }
 
 impl RouteRef {
@@ -156,9 +171,16 @@ impl SourcePath {
         self.as_str().ends_with("_index.md")
     }
 
+    /// Check if this is a page bundle's page (`article/index.md`): its
+    /// directory holds the page and its co-located assets.
+    pub fn is_bundle_index(&self) -> bool {
+        self.as_str().ends_with("/index.md")
+    }
+
     /// Convert source path to URL route.
     /// - "learn/_index.md" -> "/learn"
     /// - "learn/page.md" -> "/learn/page"
+    /// - "learn/bundle/index.md" -> "/learn/bundle"
     /// - "_index.md" -> "/"
     pub fn to_route(&self) -> Route {
         let mut path = self.as_str().to_string();
@@ -171,6 +193,9 @@ impl SourcePath {
         // Handle _index -> parent directory
         if path.ends_with("/_index") {
             path = path[..path.len() - 7].to_string();
+        } else if path.ends_with("/index") {
+            // Page bundle -> its directory
+            path = path[..path.len() - 6].to_string();
         } else if path == "_index" {
             path = String::new();
         }
@@ -206,6 +231,12 @@ mod tests {
             SourcePath::from_static("learn/page.md").to_route(),
             Route::from_static("/learn/page")
         );
+        assert_eq!(
+            SourcePath::from_static("learn/bundle/index.md").to_route(),
+            Route::from_static("/learn/bundle")
+        );
+        assert!(SourcePath::from_static("learn/bundle/index.md").is_bundle_index());
+        assert!(!SourcePath::from_static("learn/_index.md").is_bundle_index());
     }
 
     #[test]
@@ -218,6 +249,23 @@ mod tests {
         assert_eq!(Route::root().parent(), None);
     }
 
+    #[test]
+    fn test_route_with_slug() {
+        assert_eq!(
+            Route::from_static("/blog/2024-05-01-hello").with_slug("hello"),
+            Route::from_static("/blog/hello")
+        );
+        assert_eq!(
+            Route::from_static("/about-us").with_slug("/about/"),
+            Route::from_static("/about")
+        );
+        assert_eq!(Route::root().with_slug("home"), Route::root());
+        assert_eq!(
+            Route::from_static("/blog/post").with_slug(""),
+            Route::from_static("/blog/post")
+        );
+    }
+
     #[test]
     fn test_route_is_in_section() {
         let route = Route::from_static("/learn/showcases/json");
@@ -18,6 +18,11 @@
 //! The win over a flat global namespace: a bare `[[core-ml]]` on a page under
 //! `tech/` resolves to the sibling `tech/core-ml` even if `bee/impl/core-ml`
 //! also exists — the nearer scope wins, with no global ambiguity error.
+//!
+//! On a multilingual site each translation language lives under its own
+//! prefix (`/fr`). A page there resolves within its language's tree first and
+//! falls back to the default language as if it were the untranslated
+//! original; other languages' trees are never searched.
 
 use std::collections::HashMap;
 
@@ -44,6 +49,9 @@ pub struct Resolver {
     /// Each source's `(name, mount route)`, longest mount first (so the most
     /// specific mount wins when locating a route's source).
     sources: Vec<(String, String)>,
+    /// Route prefixes of the translation languages (`/fr`); the default
+    /// language is unprefixed and not listed.
+    languages: Vec<String>,
 }
 
 impl Resolver {
@@ -76,12 +84,38 @@ impl Resolver {
             sections,
             children,
             sources,
+            languages: Vec::new(),
         }
     }
 
+    /// Declare the route prefixes of translation languages (`/fr`).
+    pub fn with_language_prefixes(mut self, prefixes: impl IntoIterator<Item = String>) -> Self {
+        self.languages = prefixes
+            .into_iter()
+            .map(|p| norm(&p))
+            .filter(|p| !p.is_empty())
+            .collect();
+        self
+    }
+
     /// Resolve `target` as authored on the page at `current_route`.
     pub fn resolve(&self, current_route: &str, target: &str) -> Resolution {
         let current = norm(current_route);
+        let Some(lang_root) = self.language_root_of(&current) else {
+            return self.resolve_in(&current, target, "");
+        };
+        // A translation resolves within its language first, then like the
+        // untranslated original would.
+        match self.resolve_in(&current, target, &lang_root) {
+            Resolution::NotFound => self.resolve_in(&norm(&current[lang_root.len()..]), target, ""),
+            found => found,
+        }
+    }
+
+    /// Resolve within one language's tree, rooted at `lang_root` (`""` for
+    /// the default language).
+    fn resolve_in(&self, current: &str, target: &str, lang_root: &str) -> Resolution {
+        let current = current.to_string();
         let target = target.trim();
 
         // Optional `source:` prefix (orthogonal to every target form). Only a
@@ -91,10 +125,17 @@ impl Resolver {
         // in some other source is meaningless).
         let (source_root, rest, context) = match target.split_once(':') {
             Some((name, rest)) if self.source_mount(name).is_some() => {
-                let root = self.source_mount(name).unwrap();
+                let root = join(lang_root, &self.source_mount(name).unwrap());
                 (root.clone(), rest, root)
             }
-            _ => (self.source_root_of(&current), target, current.clone()),
+            _ => (
+                join(
+                    lang_root,
+                    &self.source_root_of(&norm(&current[lang_root.len()..])),
+                ),
+                target,
+                current.clone(),
+            ),
         };
         let rest = rest.trim();
 
@@ -129,6 +170,14 @@ impl Resolver {
         self.resolve_bare(&context, &source_root, rest)
     }
 
+    /// The prefix of the translation language `route` belongs to, if any.
+    fn language_root_of(&self, route: &str) -> Option<String> {
+        self.languages
+            .iter()
+            .find(|prefix| under(route, prefix))
+            .cloned()
+    }
+
     /// Context-first bare-slug lookup: the page's own section, then each
     /// ancestor up to the source root, then the source globally. The first scope
     /// that contains the slug decides; two matches *in that scope* is ambiguous.
@@ -151,6 +200,7 @@ impl Resolver {
             .routes
             .iter()
             .filter(|r| under(r, source_root))
+            .filter(|r| !source_root.is_empty() || self.language_root_of(r).is_none())
             .filter(|r| {
                 split_leaf(r)
                     .map(|(_, s)| slug_key(&s) == want)
@@ -419,6 +469,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn translations_resolve_within_their_language_first() {
+        let resolver = Resolver::new(
+            [
+                "/about",
+                "/guide",
+                "/guide/intro",
+                "/guide/setup",
+                "/fr",
+                "/fr/guide",
+                "/fr/guide/intro",
+            ]
+            .map(String::from),
+            ["", "/guide", "/fr", "/fr/guide"].map(String::from),
+            [(String::new(), String::new())],
+        )
+        .with_language_prefixes(["/fr".to_string()]);
+
+        assert_eq!(
+            resolver.resolve("/fr/guide", "intro"),
+            Resolution::Resolved("/fr/guide/intro".into())
+        );
+        // Untranslated targets fall back to the default language.
+        assert_eq!(
+            resolver.resolve("/fr/guide/intro", "setup"),
+            Resolution::Resolved("/guide/setup".into())
+        );
+        // The default language never reaches into a translation's tree.
+        assert_eq!(
+            resolver.resolve("/about", "intro"),
+            Resolution::Resolved("/guide/intro".into())
+        );
+    }
+
     #[test]
     fn missing_target_is_not_found() {
         assert_eq!(resolve("/tech", "nonexistent"), Resolution::NotFound);
@@ -898,6 +898,28 @@ impl TestSite {
         }
     }
 
+    /// GET a path without following redirects and return the status code and
+    /// `Location` header. Panics on a transport error.
+    pub async fn get_redirect(&self, path: &str) -> (u16, Option<String>) {
+        let url = format!("http://127.0.0.1:{}{}", self.port, path);
+        debug!("→ GET (no redirects) {}", path);
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("build reqwest client");
+        match client.get(&url).timeout(http_timeout()).send().await {
+            Ok(resp) => {
+                let location = resp
+                    .headers()
+                    .get("location")
+                    .and_then(|v| v.to_str().ok())
+                    .map(str::to_string);
+                (resp.status().as_u16(), location)
+            }
+            Err(e) => panic!("GET {path} failed: {e:?}"),
+        }
+    }
+
     /// Raw TCP probe for instrumentation. Measures connect, write, and read phases
     /// separately to diagnose where failures occur.
     ///
@@ -476,6 +476,49 @@ fn collect_tests() -> Vec<Test> {
             func: || boxed(content::missing_section_title_defaults_from_slug()),
             ignored: false,
         },
+        // frontmatter field tests
+        Test {
+            name: "build_leaves_out_drafts",
+            module: "frontmatter_fields",
+            func: || boxed(frontmatter_fields::build_leaves_out_drafts()),
+            ignored: false,
+        },
+        Test {
+            name: "serve_previews_drafts_with_marker",
+            module: "frontmatter_fields",
+            func: || boxed(frontmatter_fields::serve_previews_drafts_with_marker()),
+            ignored: false,
+        },
+        Test {
+            name: "slug_changes_route_and_at_links",
+            module: "frontmatter_fields",
+            func: || boxed(frontmatter_fields::slug_changes_route_and_at_links()),
+            ignored: false,
+        },
+        Test {
+            name: "duplicate_slug_is_an_error",
+            module: "frontmatter_fields",
+            func: || boxed(frontmatter_fields::duplicate_slug_is_an_error()),
+            ignored: false,
+        },
+        Test {
+            name: "slug_on_section_index_is_an_error",
+            module: "frontmatter_fields",
+            func: || boxed(frontmatter_fields::slug_on_section_index_is_an_error()),
+            ignored: false,
+        },
+        Test {
+            name: "build_leaves_out_scheduled_pages",
+            module: "frontmatter_fields",
+            func: || boxed(frontmatter_fields::build_leaves_out_scheduled_pages()),
+            ignored: false,
+        },
+        Test {
+            name: "serve_publishes_scheduled_page_when_due",
+            module: "frontmatter_fields",
+            func: || boxed(frontmatter_fields::serve_publishes_scheduled_page_when_due()),
+            ignored: false,
+        },
         // frontmatter schema tests
         Test {
             name: "typed_frontmatter_link_to_same_type_passes",
@@ -972,6 +1015,12 @@ fn collect_tests() -> Vec<Test> {
             func: || boxed(authoring_lsp::lsp_updates_workspace_from_watched_file_changes()),
             ignored: false,
         },
+        Test {
+            name: "lsp_route_graph_links_series_parts",
+            module: "authoring_lsp",
+            func: || boxed(authoring_lsp::lsp_route_graph_links_series_parts()),
+            ignored: false,
+        },
         // build_steps tests
         Test {
             name: "build_step_basic_command",
@@ -1139,5 +1188,195 @@ fn collect_tests() -> Vec<Test> {
             func: || boxed(shortcode_invalidation::get_media_asset_rerenders_using_page()),
             ignored: true,
         },
+        // i18n tests
+        Test {
+            name: "translations_are_served_under_language_prefix",
+            module: "i18n",
+            func: || boxed(i18n::translations_are_served_under_language_prefix()),
+            ignored: false,
+        },
+        Test {
+            name: "links_resolve_within_current_language",
+            module: "i18n",
+            func: || boxed(i18n::links_resolve_within_current_language()),
+            ignored: false,
+        },
+        Test {
+            name: "search_index_is_split_per_language",
+            module: "i18n",
+            func: || boxed(i18n::search_index_is_split_per_language()),
+            ignored: false,
+        },
+        // pagination tests
+        Test {
+            name: "first_pager_is_the_section_route",
+            module: "pagination",
+            func: || boxed(pagination::first_pager_is_the_section_route()),
+            ignored: false,
+        },
+        Test {
+            name: "later_pagers_are_served",
+            module: "pagination",
+            func: || boxed(pagination::later_pagers_are_served()),
+            ignored: false,
+        },
+        Test {
+            name: "adding_page_updates_pagers",
+            module: "pagination",
+            func: || boxed(pagination::adding_page_updates_pagers()),
+            ignored: false,
+        },
+        // feeds tests
+        Test {
+            name: "section_feeds_are_served",
+            module: "feeds",
+            func: || boxed(feeds::section_feeds_are_served()),
+            ignored: false,
+        },
+        Test {
+            name: "feeds_are_advertised_in_head",
+            module: "feeds",
+            func: || boxed(feeds::feeds_are_advertised_in_head()),
+            ignored: false,
+        },
+        Test {
+            name: "editing_page_updates_feed",
+            module: "feeds",
+            func: || boxed(feeds::editing_page_updates_feed()),
+            ignored: false,
+        },
+        // redirects tests
+        Test {
+            name: "aliases_redirect_permanently",
+            module: "redirects",
+            func: || boxed(redirects::aliases_redirect_permanently()),
+            ignored: false,
+        },
+        Test {
+            name: "site_redirects_are_served",
+            module: "redirects",
+            func: || boxed(redirects::site_redirects_are_served()),
+            ignored: false,
+        },
+        Test {
+            name: "content_routes_are_never_redirected",
+            module: "redirects",
+            func: || boxed(redirects::content_routes_are_never_redirected()),
+            ignored: false,
+        },
+        Test {
+            name: "build_writes_stubs_and_redirects_file",
+            module: "redirects",
+            func: || boxed(redirects::build_writes_stubs_and_redirects_file()),
+            ignored: false,
+        },
+        // permalink tests
+        Test {
+            name: "permalink_patterns_route_pages_and_links",
+            module: "permalinks",
+            func: || boxed(permalinks::permalink_patterns_route_pages_and_links()),
+            ignored: false,
+        },
+        Test {
+            name: "permalink_collision_is_a_duplicate_route",
+            module: "permalinks",
+            func: || boxed(permalinks::permalink_collision_is_a_duplicate_route()),
+            ignored: false,
+        },
+        Test {
+            name: "permalink_date_placeholder_needs_a_date",
+            module: "permalinks",
+            func: || boxed(permalinks::permalink_date_placeholder_needs_a_date()),
+            ignored: false,
+        },
+        // page bundle tests
+        Test {
+            name: "bundle_assets_resolve_relative_to_page",
+            module: "bundles",
+            func: || boxed(bundles::bundle_assets_resolve_relative_to_page()),
+            ignored: false,
+        },
+        Test {
+            name: "moving_bundle_moves_its_assets",
+            module: "bundles",
+            func: || boxed(bundles::moving_bundle_moves_its_assets()),
+            ignored: false,
+        },
+        Test {
+            name: "build_writes_bundle_assets",
+            module: "bundles",
+            func: || boxed(bundles::build_writes_bundle_assets()),
+            ignored: false,
+        },
+        // related pages tests
+        Test {
+            name: "related_pages_are_nearest_in_section",
+            module: "related",
+            func: || boxed(related::related_pages_are_nearest_in_section()),
+            ignored: false,
+        },
+        Test {
+            name: "opting_out_updates_related_pages",
+            module: "related",
+            func: || boxed(related::opting_out_updates_related_pages()),
+            ignored: false,
+        },
+        // series tests
+        Test {
+            name: "series_pages_link_prev_and_next",
+            module: "series",
+            func: || boxed(series::series_pages_link_prev_and_next()),
+            ignored: false,
+        },
+        Test {
+            name: "renumbering_part_reorders_series",
+            module: "series",
+            func: || boxed(series::renumbering_part_reorders_series()),
+            ignored: false,
+        },
+        // sitemap tests
+        Test {
+            name: "sitemap_lists_rendered_routes",
+            module: "sitemap",
+            func: || boxed(sitemap::sitemap_lists_rendered_routes()),
+            ignored: false,
+        },
+        Test {
+            name: "robots_txt_points_at_sitemap",
+            module: "sitemap",
+            func: || boxed(sitemap::robots_txt_points_at_sitemap()),
+            ignored: false,
+        },
+        Test {
+            name: "shipped_robots_txt_wins",
+            module: "sitemap",
+            func: || boxed(sitemap::shipped_robots_txt_wins()),
+            ignored: false,
+        },
+        // taxonomies tests
+        Test {
+            name: "taxonomy_index_lists_terms",
+            module: "taxonomies",
+            func: || boxed(taxonomies::taxonomy_index_lists_terms()),
+            ignored: false,
+        },
+        Test {
+            name: "taxonomy_term_lists_pages_newest_first",
+            module: "taxonomies",
+            func: || boxed(taxonomies::taxonomy_term_lists_pages_newest_first()),
+            ignored: false,
+        },
+        Test {
+            name: "retagging_page_updates_term_pages",
+            module: "taxonomies",
+            func: || boxed(taxonomies::retagging_page_updates_term_pages()),
+            ignored: false,
+        },
+        Test {
+            name: "retagging_extra_tags_updates_term_pages",
+            module: "taxonomies",
+            func: || boxed(taxonomies::retagging_extra_tags_updates_term_pages()),
+            ignored: false,
+        },
     ]
 }
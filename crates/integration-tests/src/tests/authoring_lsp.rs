@@ -8,6 +8,7 @@ use std::time::Duration;
 use tower_lsp::lsp_types::Url;
 
 const LIST_PAGES_COMMAND: &str = "dodeca.listPages";
+const ROUTE_GRAPH_COMMAND: &str = "dodeca.routeGraph";
 
 struct LspSite {
     _temp_dir: tempfile::TempDir,
@@ -179,6 +180,10 @@ impl LspClient {
         self.execute_command(LIST_PAGES_COMMAND, value!([]))
     }
 
+    fn route_graph(&mut self) -> Value {
+        self.execute_command(ROUTE_GRAPH_COMMAND, value!([]))
+    }
+
     fn execute_command(&mut self, command: &str, arguments: Value) -> Value {
         self.request(
             "workspace/executeCommand",
@@ -517,3 +522,35 @@ pub async fn lsp_updates_workspace_from_watched_file_changes() {
 
     assert!(routes.iter().any(|route| route == "/from-disk"));
 }
+
+pub async fn lsp_route_graph_links_series_parts() {
+    let site = LspSite::new();
+    site.write(
+        "content/guide/intro.md",
+        "+++\ntitle = \"Intro\"\n\n[series]\nname = \"Tour\"\npart = 1\n+++\n\n# Intro\n",
+    );
+    site.write(
+        "content/next-steps.md",
+        "+++\ntitle = \"Next Steps\"\n\n[series]\nname = \"Tour\"\npart = 2\n+++\n\n# Next Steps\n",
+    );
+    let mut client = site.client();
+
+    let graph = client.route_graph();
+    let node = graph
+        .as_array()
+        .expect("route graph array")
+        .iter()
+        .find(|node| node.get("route").and_then(LspJsonValue::as_str) == Some("/guide/intro"))
+        .expect("intro node")
+        .clone();
+    let series_targets = node
+        .get("outgoing")
+        .and_then(Value::as_array)
+        .expect("outgoing edges")
+        .iter()
+        .filter(|edge| edge.get("kind").and_then(LspJsonValue::as_str) == Some("series"))
+        .filter_map(|edge| edge.get("targetRoute").and_then(LspJsonValue::as_str))
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(series_targets, ["/next-steps"]);
+}
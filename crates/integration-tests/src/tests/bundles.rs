@@ -0,0 +1,129 @@
+use super::*;
+use std::path::Path;
+
+const BUNDLE_PAGE: &str = r#"+++
+title = "Article"
++++
+
+![Cover](cover.png)
+
+![Diagram](./diagram.svg)
+
+Read the [notes](notes.txt).
+"#;
+
+/// Copy the fixture's sample image and SVG into a bundle next to its page.
+fn write_bundle(fixture_dir: &Path, dir: &str) {
+    let bundle = fixture_dir.join("content").join(dir);
+    std::fs::create_dir_all(&bundle).expect("create bundle dir");
+    std::fs::write(bundle.join("index.md"), BUNDLE_PAGE).expect("write bundle page");
+    std::fs::copy(
+        fixture_dir.join("static/images/test.png"),
+        bundle.join("cover.png"),
+    )
+    .expect("copy cover");
+    std::fs::copy(
+        fixture_dir.join("static/images/test.svg"),
+        bundle.join("diagram.svg"),
+    )
+    .expect("copy diagram");
+    std::fs::write(bundle.join("notes.txt"), "bundle notes").expect("write notes");
+}
+
+pub async fn bundle_assets_resolve_relative_to_page() {
+    let site = TestSite::with_setup("sample-site", |fixture_dir| {
+        write_bundle(fixture_dir, "article");
+    });
+
+    let html = site.get("/article/").await;
+    html.assert_ok();
+    // The image goes through image processing like any static image.
+    assert!(
+        html.img_src("/article/cover.*.webp").is_some(),
+        "relative cover.png should resolve to the processed bundle image"
+    );
+
+    let svg_url = html
+        .img_src("/article/diagram.*.svg")
+        .expect("relative ./diagram.svg should resolve to the cache-busted bundle asset");
+    let svg = site.get(&svg_url).await;
+    svg.assert_ok();
+    svg.assert_contains("<svg");
+
+    let notes_url = html
+        .extract(r#"href="(/article/notes\.[^"]+\.txt)""#)
+        .expect("relative notes.txt link should resolve to the bundle asset");
+    site.get(&notes_url).await.assert_contains("bundle notes");
+}
+
+pub async fn moving_bundle_moves_its_assets() {
+    let site = TestSite::with_setup("sample-site", |fixture_dir| {
+        write_bundle(fixture_dir, "article");
+    });
+
+    let html = site.get("/article/").await;
+    let old_svg = html
+        .img_src("/article/diagram.*.svg")
+        .expect("bundle asset should be served before the move");
+
+    std::fs::rename(
+        site.fixture_dir().join("content/article"),
+        site.fixture_dir().join("content/story"),
+    )
+    .expect("move bundle");
+
+    let new_svg = site
+        .wait_until(
+            "moved bundle serves its assets",
+            Duration::from_secs(10),
+            async || {
+                let html = site.get("/story/").await;
+                (html.status == 200)
+                    .then(|| html.img_src("/story/diagram.*.svg"))
+                    .flatten()
+            },
+        )
+        .await;
+    site.get(&new_svg).await.assert_contains("<svg");
+
+    site.wait_until(
+        "old asset URL is gone",
+        Duration::from_secs(10),
+        async || (site.get(&old_svg).await.status == 404).then_some(()),
+    )
+    .await;
+}
+
+pub async fn build_writes_bundle_assets() {
+    let site = InlineSite::new(&[
+        ("_index.md", "+++\ntitle = \"Home\"\n+++\n"),
+        (
+            "article/index.md",
+            "+++\ntitle = \"Article\"\n+++\n\n![Diagram](diagram.svg)\n",
+        ),
+        (
+            "article/diagram.svg",
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"></svg>"#,
+        ),
+        ("stray.txt", "not in a bundle"),
+    ]);
+
+    site.build_in_place().assert_success();
+
+    let page = std::fs::read_to_string(site.fixture_dir.join("public/article/index.html"))
+        .expect("bundle page should be written at its directory's route");
+    let asset = std::fs::read_dir(site.fixture_dir.join("public/article"))
+        .expect("bundle output dir")
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .find(|name| name.starts_with("diagram.") && name.ends_with(".svg"))
+        .expect("bundle asset should be written, cache-busted, next to the page");
+    assert!(
+        page.contains(&format!("/article/{asset}")),
+        "page should reference the cache-busted asset {asset}: {page}"
+    );
+    assert!(
+        !site.fixture_dir.join("public/stray.txt").exists(),
+        "files outside a bundle are not published"
+    );
+}
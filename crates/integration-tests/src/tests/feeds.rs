@@ -0,0 +1,115 @@
+use super::*;
+
+const FEED_SITE_CONFIG: &str = r#"source {
+    content content
+}
+
+site {
+    output public
+    base_url "https://example.com"
+}
+"#;
+
+fn feed_site() -> TestSite {
+    TestSite::with_files(
+        "sample-site",
+        &[
+            (".config/dodeca.styx", FEED_SITE_CONFIG),
+            (
+                "content/guide/_index.md",
+                r#"+++
+title = "Guide"
+feeds = ["atom", "rss", "json"]
++++
+
+# Guide
+"#,
+            ),
+            (
+                "content/guide/advanced.md",
+                r#"+++
+title = "Advanced"
+date = 2024-05-01
++++
+
+# Advanced
+
+This is the advanced page, see [the start](/guide/getting-started/).
+"#,
+            ),
+        ],
+    )
+}
+
+pub async fn section_feeds_are_served() {
+    let site = feed_site();
+
+    let atom = site.get("/guide/atom.xml").await;
+    atom.assert_ok();
+    atom.assert_content_type("application/atom+xml");
+    atom.assert_contains("<title>Guide</title>");
+    atom.assert_contains("<title>Advanced</title>");
+    atom.assert_contains(r#"<link href="https://example.com/guide/advanced"/>"#);
+    atom.assert_contains("<published>2024-05-01T00:00:00+00:00</published>");
+    atom.assert_contains("href=&quot;https://example.com/guide/getting-started");
+
+    let rss = site.get("/guide/rss.xml").await;
+    rss.assert_ok();
+    rss.assert_content_type("application/rss+xml");
+    rss.assert_contains("<pubDate>Wed, 1 May 2024 00:00:00 +0000</pubDate>");
+
+    let json = site.get("/guide/feed.json").await;
+    json.assert_ok();
+    json.assert_content_type("application/feed+json");
+    json.assert_contains(r#""version":"https://jsonfeed.org/version/1.1""#);
+
+    let missing = site.get("/atom.xml").await;
+    assert_eq!(
+        missing.status, 404,
+        "Sections without feeds should not publish one"
+    );
+}
+
+pub async fn feeds_are_advertised_in_head() {
+    let site = feed_site();
+
+    for route in ["/guide/", "/guide/advanced/"] {
+        let html = site.get(route).await;
+        html.assert_ok();
+        html.assert_contains("application/atom+xml");
+        html.assert_contains("https://example.com/guide/feed.json");
+    }
+
+    site.get("/")
+        .await
+        .assert_not_contains("application/atom+xml");
+}
+
+pub async fn editing_page_updates_feed() {
+    let site = feed_site();
+
+    site.get("/guide/atom.xml")
+        .await
+        .assert_not_contains("Zebra");
+
+    site.write_file(
+        "content/guide/zebra.md",
+        r#"+++
+title = "Zebra"
+date = 2024-06-01
++++
+
+# Zebra
+"#,
+    );
+
+    site.wait_until(
+        "new page to appear in the feed",
+        Duration::from_secs(10),
+        async || {
+            let atom = site.get("/guide/atom.xml").await;
+            (atom.status == 200 && atom.text().contains("<title>Zebra</title>")).then_some(())
+        },
+    )
+    .await;
+}
@@ -0,0 +1,276 @@
+use super::*;
+use dodeca_search_format as fmt;
+
+const LISTING_TEMPLATE: &str = r#"<!DOCTYPE html><html><head><title>{{ section.title }}</title></head><body>
+<ul>{% for p in section.pages %}<li>{{ p.title }}</li>{% endfor %}</ul>
+{{ section.content | safe }}
+</body></html>"#;
+
+pub async fn build_leaves_out_drafts() {
+    let site = InlineSite::new(&[
+        (
+            "_index.md",
+            r#"+++
+title = "Home"
++++
+"#,
+        ),
+        (
+            "published.md",
+            r#"+++
+title = "Published Post"
++++
+
+Out in the open.
+"#,
+        ),
+        (
+            "unfinished.md",
+            r#"+++
+title = "Unfinished Post"
+draft = true
++++
+
+Not ready yet.
+"#,
+        ),
+    ]);
+    std::fs::write(
+        site.fixture_dir.join("templates/index.html"),
+        LISTING_TEMPLATE,
+    )
+    .expect("write listing template");
+
+    site.build_in_place().assert_success();
+
+    let public = site.fixture_dir.join("public");
+    assert!(public.join("published/index.html").exists());
+    assert!(
+        !public.join("unfinished/index.html").exists(),
+        "drafts must not be written by `ddc build`"
+    );
+
+    let home = std::fs::read_to_string(public.join("index.html")).expect("read home");
+    assert!(home.contains("Published Post"), "{home}");
+    assert!(!home.contains("Unfinished Post"), "{home}");
+
+    let meta: fmt::SearchMeta =
+        fmt::decode(&std::fs::read(public.join("search/meta")).expect("read search meta"))
+            .expect("decode meta");
+    let urls: Vec<&str> = meta.docs.iter().map(|d| d.url.as_str()).collect();
+    assert!(urls.contains(&"/published/"), "{urls:?}");
+    assert!(
+        !urls.iter().any(|url| url.contains("unfinished")),
+        "{urls:?}"
+    );
+}
+
+pub async fn serve_previews_drafts_with_marker() {
+    let site = TestSite::with_files(
+        "sample-site",
+        &[(
+            "content/unfinished.md",
+            r#"+++
+title = "Unfinished Post"
+draft = true
++++
+
+Not ready yet.
+"#,
+        )],
+    );
+
+    let html = site.get("/unfinished/").await;
+    html.assert_ok();
+    html.assert_contains("Not ready yet.");
+    html.assert_contains(r#"id="dodeca-draft-marker""#);
+
+    site.get("/guide/getting-started/")
+        .await
+        .assert_not_contains("dodeca-draft-marker");
+}
+
+pub async fn slug_changes_route_and_at_links() {
+    let site = TestSite::with_files(
+        "sample-site",
+        &[
+            (
+                "content/2024-05-01-hello.md",
+                r#"+++
+title = "Hello"
+slug = "hello"
++++
+
+Hello from a slugged page.
+"#,
+            ),
+            (
+                "content/linker.md",
+                r#"+++
+title = "Linker"
++++
+
+Say [hello](@/2024-05-01-hello.md).
+"#,
+            ),
+        ],
+    );
+
+    site.get("/hello/")
+        .await
+        .assert_contains("Hello from a slugged page.");
+    assert_eq!(site.get("/2024-05-01-hello/").await.status, 404);
+    site.get("/linker/")
+        .await
+        .assert_contains(r#"href="/hello/""#);
+}
+
+pub async fn duplicate_slug_is_an_error() {
+    let site = TestSite::with_files(
+        "sample-site",
+        &[
+            (
+                "content/first-hello.md",
+                r#"+++
+title = "First Hello"
+slug = "hello"
++++
+"#,
+            ),
+            (
+                "content/second-hello.md",
+                r#"+++
+title = "Second Hello"
+slug = "hello"
++++
+"#,
+            ),
+        ],
+    );
+
+    let html = site.get("/hello/").await;
+    html.assert_contains("is also claimed by");
+    html.assert_contains("first-hello.md");
+}
+
+pub async fn slug_on_section_index_is_an_error() {
+    let site = TestSite::with_files(
+        "sample-site",
+        &[(
+            "content/guide/_index.md",
+            r#"+++
+title = "Guide"
+slug = "docs"
++++
+"#,
+        )],
+    );
+
+    site.get("/guide/")
+        .await
+        .assert_contains("is not supported on _index.md");
+}
+
+pub async fn build_leaves_out_scheduled_pages() {
+    let site = InlineSite::new(&[
+        (
+            "_index.md",
+            r#"+++
+title = "Home"
++++
+"#,
+        ),
+        (
+            "released.md",
+            r#"+++
+title = "Released Post"
+publish_at = 2020-01-01
++++
+
+Already out.
+"#,
+        ),
+        (
+            "scheduled.md",
+            r#"+++
+title = "Scheduled Post"
+publish_at = "2999-01-01T09:00:00Z"
++++
+
+Not yet.
+"#,
+        ),
+    ]);
+    std::fs::write(
+        site.fixture_dir.join("templates/index.html"),
+        LISTING_TEMPLATE,
+    )
+    .expect("write listing template");
+
+    site.build_in_place().assert_success();
+
+    let public = site.fixture_dir.join("public");
+    assert!(public.join("released/index.html").exists());
+    assert!(
+        !public.join("scheduled/index.html").exists(),
+        "future-dated pages must not be written by `ddc build`"
+    );
+
+    let home = std::fs::read_to_string(public.join("index.html")).expect("read home");
+    assert!(home.contains("Released Post"), "{home}");
+    assert!(!home.contains("Scheduled Post"), "{home}");
+}
+
+pub async fn serve_publishes_scheduled_page_when_due() {
+    let site = TestSite::with_files(
+        "sample-site",
+        &[(
+            "content/guide/someday.md",
+            r#"+++
+title = "Someday"
+publish_at = "2999-01-01T09:00:00Z"
++++
+
+Far off.
+"#,
+        )],
+    );
+
+    assert_eq!(site.get("/guide/someday/").await.status, 404);
+    site.get("/sitemap.xml")
+        .await
+        .assert_not_contains("/guide/someday");
+
+    // Scheduled a few seconds out: hidden now, live once the clock passes it,
+    // with no further edits.
+    let publish_at = (chrono::Utc::now() + chrono::Duration::seconds(4))
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    site.write_file(
+        "content/guide/soon.md",
+        &format!(
+            r#"+++
+title = "Soon"
+publish_at = "{publish_at}"
++++
+
+Just released.
+"#
+        ),
+    );
+    assert_eq!(site.get("/guide/soon/").await.status, 404);
+
+    site.wait_until(
+        "scheduled page to be published",
+        Duration::from_secs(30),
+        async || {
+            let resp = site.get("/guide/soon/").await;
+            (resp.status == 200).then_some(resp)
+        },
+    )
+    .await
+    .assert_contains("Just released.");
+    site.get("/sitemap.xml")
+        .await
+        .assert_contains("/guide/soon");
+    assert_eq!(site.get("/guide/someday/").await.status, 404);
+}
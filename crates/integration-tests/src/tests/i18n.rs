@@ -0,0 +1,167 @@
+use super::*;
+use dodeca_search_format as fmt;
+
+const I18N_SITE_CONFIG: &str = r#"source {
+    content content
+}
+
+site {
+    output public
+    default_language en
+    languages {
+        en {
+            name English
+        }
+        fr {
+            name Français
+        }
+    }
+}
+"#;
+
+const I18N_PAGE_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="{{ page.lang }}">
+<head><title>{{ page.title }}</title></head>
+<body>
+  <h1>{{ page.title }}</h1>
+  <ul class="translations">
+  {% for version in page.translations %}
+    <li><a class="translation" hreflang="{{ version.lang }}" href="{{ version.path }}">{{ version.name }}</a></li>
+  {% endfor %}
+  </ul>
+  {{ page.content | safe }}
+</body>
+</html>
+"#;
+
+fn i18n_site() -> TestSite {
+    TestSite::with_files(
+        "sample-site",
+        &[
+            (".config/dodeca.styx", I18N_SITE_CONFIG),
+            ("templates/page.html", I18N_PAGE_TEMPLATE),
+            (
+                "content/guide/getting-started.md",
+                r#"+++
+title = "Getting Started"
++++
+
+Read [[advanced]] next.
+"#,
+            ),
+            (
+                "content/guide/_index.fr.md",
+                r#"+++
+title = "Guide"
++++
+"#,
+            ),
+            (
+                "content/guide/getting-started.fr.md",
+                r#"+++
+title = "Premiers pas"
++++
+
+Lisez [[advanced]], puis [la suite](@/guide/advanced.md) et [[mermaid-test]].
+L'ornithorynque est un mot que seule cette page utilise.
+"#,
+            ),
+            (
+                "content/guide/advanced.fr.md",
+                r#"+++
+title = "Avancé"
++++
+
+# Avancé
+"#,
+            ),
+        ],
+    )
+}
+
+/// Every `href` in `html`, without trailing slashes.
+fn hrefs(html: &str) -> Vec<String> {
+    html.split("href=\"")
+        .skip(1)
+        .filter_map(|rest| rest.split('"').next())
+        .map(|href| href.trim_end_matches('/').to_string())
+        .collect()
+}
+
+pub async fn translations_are_served_under_language_prefix() {
+    let site = i18n_site();
+
+    let fr = site.get("/fr/guide/getting-started/").await;
+    fr.assert_ok();
+    fr.assert_contains(r#"<html lang="fr">"#);
+    fr.assert_contains("<h1>Premiers pas</h1>");
+    fr.assert_contains(
+        r#"<a class="translation" hreflang="en" href="/guide/getting-started">English</a>"#,
+    );
+    fr.assert_contains(
+        r#"<a class="translation" hreflang="fr" href="/fr/guide/getting-started">Français</a>"#,
+    );
+
+    let en = site.get("/guide/getting-started/").await;
+    en.assert_contains(r#"<html lang="en">"#);
+    en.assert_contains(r#"<link rel="alternate" hreflang="fr" href="/fr/guide/getting-started">"#);
+    en.assert_contains(
+        r#"<link rel="alternate" hreflang="x-default" href="/guide/getting-started">"#,
+    );
+
+    // Untranslated pages advertise no alternates.
+    let untranslated = site.get("/guide/mermaid-test/").await;
+    untranslated.assert_ok();
+    untranslated.assert_not_contains("hreflang");
+}
+
+pub async fn links_resolve_within_current_language() {
+    let site = i18n_site();
+
+    let fr = hrefs(site.get("/fr/guide/getting-started/").await.text());
+    assert!(
+        fr.iter().filter(|h| *h == "/fr/guide/advanced").count() >= 2,
+        "wiki and @/ links should land on the French page, found {fr:?}"
+    );
+    assert!(
+        fr.iter().any(|h| h == "/guide/mermaid-test"),
+        "untranslated targets fall back to the default language, found {fr:?}"
+    );
+
+    let en = hrefs(site.get("/guide/getting-started/").await.text());
+    assert!(
+        en.iter().any(|h| h == "/guide/advanced"),
+        "the default language never links into a translation, found {en:?}"
+    );
+    assert!(!en.iter().any(|h| h.starts_with("/fr/guide/advanced")));
+}
+
+pub async fn search_index_is_split_per_language() {
+    let site = i18n_site();
+
+    let fr: fmt::SearchMeta =
+        fmt::decode(&site.get_bytes("/fr/search/meta").await).expect("decode fr meta");
+    let fr_urls: Vec<&str> = fr.docs.iter().map(|d| d.url.as_str()).collect();
+    assert!(
+        fr_urls.contains(&"/fr/guide/getting-started/"),
+        "{fr_urls:?}"
+    );
+    assert!(
+        fr_urls.iter().all(|url| url.starts_with("/fr/")),
+        "{fr_urls:?}"
+    );
+    assert!(
+        fr.docs
+            .iter()
+            .all(|d| d.fragment.starts_with("/fr/search/"))
+    );
+
+    let en: fmt::SearchMeta =
+        fmt::decode(&site.get_bytes("/search/meta").await).expect("decode meta");
+    let en_urls: Vec<&str> = en.docs.iter().map(|d| d.url.as_str()).collect();
+    assert!(en_urls.contains(&"/guide/getting-started/"), "{en_urls:?}");
+    assert!(
+        !en_urls.iter().any(|url| url.starts_with("/fr/")),
+        "{en_urls:?}"
+    );
+}
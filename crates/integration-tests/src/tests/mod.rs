@@ -6,6 +6,7 @@ pub mod authoring_lsp;
 pub mod basic;
 pub mod boot_contract;
 pub mod build_steps;
+pub mod bundles;
 pub mod cache_busting;
 pub mod code_execution;
 pub mod content;
@@ -13,17 +14,27 @@ pub mod coverage;
 pub mod dead_links;
 pub mod editor;
 pub mod error_detection;
+pub mod feeds;
+pub mod frontmatter_fields;
 pub mod frontmatter_schemas;
+pub mod i18n;
 pub mod internal_links;
 pub mod livereload;
 pub mod mermaid;
 pub mod multi_source;
+pub mod pagination;
+pub mod permalinks;
 pub mod picante_cache;
+pub mod redirects;
+pub mod related;
 pub mod renames;
 pub mod rendered_markdown;
 pub mod sass;
 pub mod search;
 pub mod section_pages;
+pub mod series;
 pub mod shortcode_invalidation;
+pub mod sitemap;
 pub mod static_assets;
+pub mod taxonomies;
 pub mod templates;
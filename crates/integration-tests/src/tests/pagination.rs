@@ -0,0 +1,92 @@
+use super::*;
+
+const PAGINATED_SECTION_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html>
+<head><title>{{ section.title }}</title></head>
+<body>
+  <p class="pager">Pager {{ paginator.current_index }} of {{ paginator.number_pagers }}</p>
+  <ul>
+  {% for page in paginator.pages %}
+    <li><a class="listed" href="{{ page.path }}">{{ page.title }}</a></li>
+  {% endfor %}
+  </ul>
+  {% if paginator.previous %}<a class="prev" href="{{ paginator.previous }}">Newer</a>{% endif %}
+  {% if paginator.next %}<a class="next" href="{{ paginator.next }}">Older</a>{% endif %}
+</body>
+</html>
+"#;
+
+fn paginated_site() -> TestSite {
+    TestSite::with_files(
+        "sample-site",
+        &[
+            (
+                "content/guide/_index.md",
+                r#"+++
+title = "Guide"
+paginate_by = 2
++++
+
+# Guide
+"#,
+            ),
+            ("templates/section.html", PAGINATED_SECTION_TEMPLATE),
+        ],
+    )
+}
+
+pub async fn first_pager_is_the_section_route() {
+    let site = paginated_site();
+
+    let html = site.get("/guide/").await;
+    html.assert_ok();
+    html.assert_contains("Pager 1 of 2");
+    html.assert_contains("Advanced");
+    html.assert_contains("Getting Started");
+    html.assert_not_contains("Mermaid Test");
+    html.assert_contains(r#"class="next" href="/guide/page/2"#);
+    html.assert_not_contains(r#"class="prev""#);
+}
+
+pub async fn later_pagers_are_served() {
+    let site = paginated_site();
+
+    let html = site.get("/guide/page/2/").await;
+    html.assert_ok();
+    html.assert_contains("Pager 2 of 2");
+    html.assert_contains("Mermaid Test");
+    html.assert_not_contains("Getting Started");
+    html.assert_contains(r#"class="prev" href="/guide"#);
+    html.assert_not_contains(r#"class="next""#);
+
+    let past_end = site.get("/guide/page/3/").await;
+    assert_eq!(past_end.status, 404, "Pagers past the end should not exist");
+}
+
+pub async fn adding_page_updates_pagers() {
+    let site = paginated_site();
+
+    site.get("/guide/page/2/")
+        .await
+        .assert_not_contains("Zebra");
+
+    site.write_file(
+        "content/guide/zebra.md",
+        r#"+++
+title = "Zebra"
++++
+
+# Zebra
+"#,
+    );
+
+    site.wait_until(
+        "new page to appear on the second pager",
+        Duration::from_secs(10),
+        async || {
+            let html = site.get("/guide/page/2/").await;
+            (html.status == 200 && html.text().contains("Zebra")).then_some(())
+        },
+    )
+    .await;
+}
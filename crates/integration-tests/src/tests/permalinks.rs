@@ -0,0 +1,130 @@
+use super::*;
+
+const PERMALINKS_CONFIG: &str = r#"source {
+    content content
+}
+
+site {
+    output public
+    permalinks {
+        /blog "/blog/{year}/{month}/{slug}/"
+        recipe "/recipes/{slug}/"
+    }
+}
+"#;
+
+const BLOG_INDEX: &str = r#"+++
+title = "Blog"
++++
+"#;
+
+pub async fn permalink_patterns_route_pages_and_links() {
+    let site = TestSite::with_files(
+        "sample-site",
+        &[
+            (".config/dodeca.styx", PERMALINKS_CONFIG),
+            ("content/blog/_index.md", BLOG_INDEX),
+            (
+                "content/blog/advanced.md",
+                r#"+++
+title = "Advanced"
+date = 2024-05-01
++++
+
+Dated by pattern.
+"#,
+            ),
+            (
+                "content/blog/soup.md",
+                r#"+++
+title = "Soup"
+slug = "tomato-soup"
+
+[extra]
+type = "recipe"
++++
+
+Typed by pattern.
+"#,
+            ),
+            (
+                "content/linker.md",
+                r#"+++
+title = "Linker"
++++
+
+Read [advanced](@/blog/advanced.md) and [soup](@/blog/soup.md).
+"#,
+            ),
+        ],
+    );
+
+    site.get("/blog/2024/05/advanced/")
+        .await
+        .assert_contains("Dated by pattern.");
+    assert_eq!(site.get("/blog/advanced/").await.status, 404);
+
+    // The page type's pattern wins over the section's; `slug` fills `{slug}`.
+    site.get("/recipes/tomato-soup/")
+        .await
+        .assert_contains("Typed by pattern.");
+
+    let linker = site.get("/linker/").await;
+    linker.assert_contains(r#"href="/blog/2024/05/advanced/""#);
+    linker.assert_contains(r#"href="/recipes/tomato-soup/""#);
+
+    // Sections keep their directory routes.
+    site.get("/blog/").await.assert_ok();
+}
+
+pub async fn permalink_collision_is_a_duplicate_route() {
+    let site = TestSite::with_files(
+        "sample-site",
+        &[
+            (".config/dodeca.styx", PERMALINKS_CONFIG),
+            ("content/blog/_index.md", BLOG_INDEX),
+            (
+                "content/blog/advanced.md",
+                r#"+++
+title = "Advanced"
+date = 2024-05-01
+slug = "hello"
++++
+"#,
+            ),
+            (
+                "content/blog/getting-started.md",
+                r#"+++
+title = "Getting Started"
+date = 2024-05-20
+slug = "hello"
++++
+"#,
+            ),
+        ],
+    );
+
+    let html = site.get("/blog/2024/05/hello/").await;
+    html.assert_contains("is also claimed by");
+}
+
+pub async fn permalink_date_placeholder_needs_a_date() {
+    let site = TestSite::with_files(
+        "sample-site",
+        &[
+            (".config/dodeca.styx", PERMALINKS_CONFIG),
+            ("content/blog/_index.md", BLOG_INDEX),
+            (
+                "content/blog/advanced.md",
+                r#"+++
+title = "Advanced"
++++
+"#,
+            ),
+        ],
+    );
+
+    site.get("/blog/")
+        .await
+        .assert_contains("but the page has no `date`");
+}
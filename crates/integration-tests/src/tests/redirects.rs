@@ -0,0 +1,110 @@
+use super::*;
+
+const REDIRECT_SITE_CONFIG: &str = r#"source {
+    content content
+}
+
+site {
+    output public
+    redirects {
+        /manual/ /guide/
+        /docs "https://docs.example.com/"
+    }
+}
+"#;
+
+fn redirect_site() -> TestSite {
+    TestSite::with_files(
+        "sample-site",
+        &[
+            (".config/dodeca.styx", REDIRECT_SITE_CONFIG),
+            (
+                "content/guide/advanced.md",
+                r#"+++
+title = "Advanced"
+aliases = ["/old/advanced/", "/legacy"]
++++
+
+# Advanced
+"#,
+            ),
+        ],
+    )
+}
+
+pub async fn aliases_redirect_permanently() {
+    let site = redirect_site();
+
+    for old in ["/old/advanced/", "/old/advanced", "/legacy/"] {
+        let (status, location) = site.get_redirect(old).await;
+        assert_eq!(status, 301, "{old} should be a permanent redirect");
+        assert_eq!(location.as_deref(), Some("/guide/advanced"));
+    }
+
+    let followed = site.get("/old/advanced/").await;
+    followed.assert_ok();
+    followed.assert_contains("Advanced");
+}
+
+pub async fn site_redirects_are_served() {
+    let site = redirect_site();
+
+    let (status, location) = site.get_redirect("/manual/").await;
+    assert_eq!(status, 301);
+    assert_eq!(location.as_deref(), Some("/guide/"));
+
+    let (status, location) = site.get_redirect("/docs").await;
+    assert_eq!(status, 301);
+    assert_eq!(location.as_deref(), Some("https://docs.example.com/"));
+}
+
+pub async fn content_routes_are_never_redirected() {
+    let site = TestSite::with_files(
+        "sample-site",
+        &[(
+            "content/guide/advanced.md",
+            r#"+++
+title = "Advanced"
+aliases = ["/guide/getting-started/"]
++++
+
+# Advanced
+"#,
+        )],
+    );
+
+    let (status, _) = site.get_redirect("/guide/getting-started/").await;
+    assert_eq!(status, 200, "a rendered page should win over an alias");
+}
+
+pub async fn build_writes_stubs_and_redirects_file() {
+    let site = InlineSite::new(&[
+        (
+            "_index.md",
+            r#"+++
+title = "Home"
++++
+
+See [the old post](/old/post/)."#,
+        ),
+        (
+            "post.md",
+            r#"+++
+title = "Post"
+aliases = ["/old/post/"]
++++
+
+Moved here."#,
+        ),
+    ]);
+
+    site.build_in_place().assert_success();
+
+    let stub = std::fs::read_to_string(site.fixture_dir.join("public/old/post/index.html"))
+        .expect("alias should get a redirect stub");
+    assert!(stub.contains(r#"<meta http-equiv="refresh" content="0; url=/post">"#));
+
+    let redirects = std::fs::read_to_string(site.fixture_dir.join("public/_redirects"))
+        .expect("_redirects should be written");
+    assert_eq!(redirects, "/old/post /post 301\n");
+}
@@ -0,0 +1,142 @@
+use super::*;
+
+const RELATED_CONFIG: &str = r#"source {
+    content content
+}
+
+site {
+    output public
+    related {
+        k 1
+        within section
+    }
+}
+"#;
+
+const RELATED_PAGE_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html>
+<head><title>{{ page.title }}</title></head>
+<body>
+  <h1>{{ page.title }}</h1>
+  {{ page.content | safe }}
+  <ul class="related">
+  {% for other in page.related %}
+    <li><a class="related" href="{{ other.path }}">{{ other.title }}</a></li>
+  {% endfor %}
+  </ul>
+</body>
+</html>
+"#;
+
+const PEPPERS: &str = r#"+++
+title = "Peppers"
++++
+
+Growing peppers in the vegetable garden: sow seeds indoors, plant the
+seedlings out in sunny beds after the last frost, water the soil deeply and
+harvest the ripe fruit in late summer.
+"#;
+
+fn related_site() -> TestSite {
+    TestSite::with_files(
+        "sample-site",
+        &[
+            (".config/dodeca.styx", RELATED_CONFIG),
+            ("templates/page.html", RELATED_PAGE_TEMPLATE),
+            ("content/garden/_index.md", "+++\ntitle = \"Garden\"\n+++\n"),
+            (
+                "content/garden/tomatoes.md",
+                r#"+++
+title = "Tomatoes"
++++
+
+Growing tomatoes in the vegetable garden: sow seeds indoors, plant the
+seedlings out in sunny beds after the last frost, water the soil deeply and
+harvest the ripe fruit in summer.
+"#,
+            ),
+            ("content/garden/peppers.md", PEPPERS),
+            (
+                "content/garden/taxes.md",
+                r#"+++
+title = "Taxes"
++++
+
+Filing an income tax return: collect invoices and receipts, fill in the
+declaration form, pay what you owe to the revenue office before the deadline.
+"#,
+            ),
+            (
+                "content/garden/tomato-notes.md",
+                r#"+++
+title = "Tomato Notes"
+related = false
++++
+
+Growing tomatoes in the vegetable garden: sow seeds indoors, plant the
+seedlings out in sunny beds after the last frost, water the soil deeply and
+harvest the ripe fruit in summer.
+"#,
+            ),
+            // Outside the section, so `within section` keeps it out.
+            (
+                "content/tomato-guide.md",
+                r#"+++
+title = "Tomato Guide"
++++
+
+Growing tomatoes in the vegetable garden: sow seeds indoors, plant the
+seedlings out in sunny beds after the last frost, water the soil deeply and
+harvest the ripe fruit in summer.
+"#,
+            ),
+        ],
+    )
+}
+
+pub async fn related_pages_are_nearest_in_section() {
+    let site = related_site();
+
+    let tomatoes = site.get("/garden/tomatoes/").await;
+    tomatoes.assert_ok();
+    tomatoes.assert_contains(r#"<a class="related" href="/garden/peppers">Peppers</a>"#);
+    // `k 1`: the nearest page only.
+    tomatoes.assert_not_contains(r#"href="/garden/taxes""#);
+    // Opted out, and outside the section.
+    tomatoes.assert_not_contains(r#"href="/garden/tomato-notes""#);
+    tomatoes.assert_not_contains(r#"href="/tomato-guide""#);
+
+    // An opted-out page gets no suggestions either.
+    site.get("/garden/tomato-notes/")
+        .await
+        .assert_not_contains(r#"<a class="related""#);
+}
+
+pub async fn opting_out_updates_related_pages() {
+    let site = related_site();
+
+    site.get("/garden/tomatoes/")
+        .await
+        .assert_contains(r#"href="/garden/peppers">Peppers</a>"#);
+
+    site.write_file(
+        "content/garden/peppers.md",
+        &PEPPERS.replace(
+            "title = \"Peppers\"",
+            "title = \"Peppers\"\nrelated = false",
+        ),
+    );
+
+    site.wait_until(
+        "peppers to leave related pages",
+        Duration::from_secs(10),
+        async || {
+            let tomatoes = site.get("/garden/tomatoes/").await;
+            tomatoes
+                .text()
+                .contains(r#"<a class="related" href="/garden/taxes">Taxes</a>"#)
+                .then_some(())
+        },
+    )
+    .await;
+}
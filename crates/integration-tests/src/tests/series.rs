@@ -0,0 +1,133 @@
+use super::*;
+
+const SERIES_PAGE_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html>
+<head><title>{{ page.title }}</title></head>
+<body>
+  <h1>{{ page.title }}</h1>
+  {% if page.series %}
+  <p class="series">{{ page.series.name }}: part {{ page.series.index }} of {{ page.series.total }}</p>
+  <ol>
+  {% for part in page.series.pages %}
+    <li><a href="{{ part.path }}">{{ part.title }}</a></li>
+  {% endfor %}
+  </ol>
+  {% if page.series.prev %}<a class="prev" href="{{ page.series.prev.path }}">Previous</a>{% endif %}
+  {% if page.series.next %}<a class="next" href="{{ page.series.next.path }}">Next</a>{% endif %}
+  {% endif %}
+  {{ page.content | safe }}
+</body>
+</html>
+"#;
+
+fn series_site() -> TestSite {
+    TestSite::with_files(
+        "sample-site",
+        &[
+            ("templates/page.html", SERIES_PAGE_TEMPLATE),
+            (
+                "content/new-page.md",
+                r#"+++
+title = "New Page"
+
+[series]
+name = "Tour"
+part = 1
++++
+
+The tour starts outside the guide.
+"#,
+            ),
+            (
+                "content/guide/getting-started.md",
+                r#"+++
+title = "Getting Started"
+
+[series]
+name = "Tour"
+part = 2
++++
+
+# Getting Started
+"#,
+            ),
+            (
+                "content/guide/advanced.md",
+                r#"+++
+title = "Advanced"
+
+[series]
+name = "Tour"
+part = 3
++++
+
+# Advanced
+"#,
+            ),
+        ],
+    )
+}
+
+pub async fn series_pages_link_prev_and_next() {
+    let site = series_site();
+
+    let middle = site.get("/guide/getting-started/").await;
+    middle.assert_ok();
+    middle.assert_contains("Tour: part 2 of 3");
+    middle.assert_contains(r#"<a class="prev" href="/new-page">Previous</a>"#);
+    middle.assert_contains(r#"<a class="next" href="/guide/advanced">Next</a>"#);
+
+    let first = site.get("/new-page/").await;
+    first.assert_contains("Tour: part 1 of 3");
+    first.assert_not_contains(r#"class="prev""#);
+
+    let last = site.get("/guide/advanced/").await;
+    last.assert_contains("Tour: part 3 of 3");
+    last.assert_not_contains(r#"class="next""#);
+}
+
+pub async fn renumbering_part_reorders_series() {
+    let site = series_site();
+
+    site.get("/guide/advanced/")
+        .await
+        .assert_contains("Tour: part 3 of 3");
+
+    site.write_file(
+        "content/guide/advanced.md",
+        r#"+++
+title = "Advanced"
+
+[series]
+name = "Tour"
+part = 1
++++
+
+# Advanced
+"#,
+    );
+    site.write_file(
+        "content/new-page.md",
+        r#"+++
+title = "New Page"
+
+[series]
+name = "Tour"
+part = 4
++++
+
+The tour now ends outside the guide.
+"#,
+    );
+
+    site.wait_until("series to reorder", Duration::from_secs(10), async || {
+        let first = site.get("/guide/advanced/").await;
+        let last = site.get("/new-page/").await;
+        (first.text().contains("Tour: part 1 of 3")
+            && last
+                .text()
+                .contains(r#"<a class="prev" href="/guide/getting-started">Previous</a>"#))
+        .then_some(())
+    })
+    .await;
+}
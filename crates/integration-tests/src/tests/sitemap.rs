@@ -0,0 +1,90 @@
+use super::*;
+
+const SITEMAP_SITE_CONFIG: &str = r#"source {
+    content content
+}
+
+site {
+    output public
+    base_url "https://example.com"
+}
+"#;
+
+const STABLE_ROBOTS_CONFIG: &str = r#"source {
+    content content
+}
+
+site {
+    output public
+    base_url "https://example.com"
+    stable_assets (
+        robots.txt
+    )
+}
+"#;
+
+fn sitemap_site() -> TestSite {
+    TestSite::with_files(
+        "sample-site",
+        &[
+            (".config/dodeca.styx", SITEMAP_SITE_CONFIG),
+            (
+                "content/guide/advanced.md",
+                r#"+++
+title = "Advanced"
+sitemap = false
++++
+
+# Advanced
+"#,
+            ),
+            (
+                "content/guide/getting-started.md",
+                r#"+++
+title = "Getting Started"
+sitemap_priority = 0.9
++++
+
+# Getting Started
+"#,
+            ),
+        ],
+    )
+}
+
+pub async fn sitemap_lists_rendered_routes() {
+    let site = sitemap_site();
+
+    let sitemap = site.get("/sitemap.xml").await;
+    sitemap.assert_ok();
+    sitemap.assert_content_type("application/xml");
+    sitemap.assert_contains("<loc>https://example.com/</loc>");
+    sitemap.assert_contains("<loc>https://example.com/guide</loc>");
+    sitemap.assert_contains("<loc>https://example.com/guide/getting-started</loc>");
+    sitemap.assert_contains("<priority>0.9</priority>");
+    sitemap.assert_contains("<lastmod>");
+    sitemap.assert_not_contains("/guide/advanced");
+}
+
+pub async fn robots_txt_points_at_sitemap() {
+    let site = sitemap_site();
+
+    let robots = site.get("/robots.txt").await;
+    robots.assert_ok();
+    robots.assert_contains("Sitemap: https://example.com/sitemap.xml");
+}
+
+pub async fn shipped_robots_txt_wins() {
+    let site = TestSite::with_files(
+        "sample-site",
+        &[
+            (".config/dodeca.styx", STABLE_ROBOTS_CONFIG),
+            ("static/robots.txt", "User-agent: *\nDisallow: /private/\n"),
+        ],
+    );
+
+    let robots = site.get("/robots.txt").await;
+    robots.assert_ok();
+    robots.assert_contains("Disallow: /private/");
+    robots.assert_not_contains("Sitemap:");
+}
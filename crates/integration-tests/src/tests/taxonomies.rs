@@ -0,0 +1,183 @@
+use super::*;
+
+const TAXONOMY_CONFIG: &str = r#"source {
+    content content
+}
+
+site {
+    output public
+    taxonomies {
+        tags {}
+    }
+}
+"#;
+
+const TAXONOMY_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html>
+<head><title>{{ taxonomy.title }}</title></head>
+<body>
+  <ul>
+  {% for term in taxonomy.terms %}
+    <li><a class="term" href="{{ term.path }}">{{ term.name }} ({{ term.page_count }})</a></li>
+  {% endfor %}
+  </ul>
+</body>
+</html>
+"#;
+
+const TERM_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html>
+<head><title>{{ term.name }} - {{ taxonomy.title }}</title></head>
+<body>
+  <a class="index" href="{{ taxonomy.path }}">All {{ taxonomy.title }}</a>
+  <ul>
+  {% for page in term.pages %}
+    <li><a class="tagged" href="{{ page.path }}">{{ page.title }}</a></li>
+  {% endfor %}
+  </ul>
+</body>
+</html>
+"#;
+
+fn taxonomy_site() -> TestSite {
+    TestSite::with_files(
+        "sample-site",
+        &[
+            (".config/dodeca.styx", TAXONOMY_CONFIG),
+            ("templates/taxonomy.html", TAXONOMY_TEMPLATE),
+            ("templates/taxonomy_term.html", TERM_TEMPLATE),
+            (
+                "content/guide/older.md",
+                r#"+++
+title = "Older Post"
+date = 2024-01-01
+
+[taxonomies]
+tags = ["Rust", "async"]
++++
+
+# Older
+"#,
+            ),
+            (
+                "content/guide/newer.md",
+                r#"+++
+title = "Newer Post"
+date = 2024-06-01
+
+[taxonomies]
+tags = ["Rust"]
++++
+
+# Newer
+"#,
+            ),
+            (
+                "content/guide/unrelated.md",
+                r#"+++
+title = "Unrelated Post"
+
+[taxonomies]
+tags = ["go"]
++++
+
+# Unrelated
+"#,
+            ),
+            (
+                "content/guide/legacy.md",
+                r#"+++
+title = "Legacy Post"
+
+[extra]
+tags = ["python"]
++++
+
+# Legacy
+"#,
+            ),
+        ],
+    )
+}
+
+pub async fn taxonomy_index_lists_terms() {
+    let site = taxonomy_site();
+
+    let html = site.get("/tags/").await;
+    html.assert_ok();
+    html.assert_contains("<title>Tags</title>");
+    html.assert_contains(r#"href="/tags/rust"#);
+    html.assert_contains("Rust (2)");
+    html.assert_contains(r#"href="/tags/async"#);
+    html.assert_contains("async (1)");
+}
+
+pub async fn taxonomy_term_lists_pages_newest_first() {
+    let site = taxonomy_site();
+
+    let html = site.get("/tags/rust/").await;
+    html.assert_ok();
+    html.assert_contains("<title>Rust - Tags</title>");
+    let text = html.text();
+    let newer = text.find("Newer Post").expect("newer page listed");
+    let older = text.find("Older Post").expect("older page listed");
+    assert!(newer < older, "term pages should be newest first:\n{text}");
+
+    let async_html = site.get("/tags/async/").await;
+    async_html.assert_ok();
+    async_html.assert_contains("Older Post");
+    async_html.assert_not_contains("Newer Post");
+}
+
+pub async fn retagging_page_updates_term_pages() {
+    let site = taxonomy_site();
+
+    site.get("/tags/async/")
+        .await
+        .assert_not_contains("Newer Post");
+
+    site.modify_file("content/guide/newer.md", |content| {
+        content.replace(r#"tags = ["Rust"]"#, r#"tags = ["Rust", "async"]"#)
+    });
+
+    site.wait_until(
+        "newer page to appear under /tags/async/",
+        Duration::from_secs(10),
+        async || {
+            let html = site.get("/tags/async/").await;
+            html.text().contains("Newer Post").then_some(())
+        },
+    )
+    .await;
+}
+
+pub async fn retagging_extra_tags_updates_term_pages() {
+    let site = taxonomy_site();
+
+    site.get("/tags/python/")
+        .await
+        .assert_contains("Legacy Post");
+    site.get("/tags/async/")
+        .await
+        .assert_not_contains("Legacy Post");
+
+    site.modify_file("content/guide/legacy.md", |content| {
+        content.replace(r#"tags = ["python"]"#, r#"tags = ["async"]"#)
+    });
+
+    site.wait_until(
+        "legacy page to move from /tags/python/ to /tags/async/",
+        Duration::from_secs(10),
+        async || {
+            let html = site.get("/tags/async/").await;
+            html.text().contains("Legacy Post").then_some(())
+        },
+    )
+    .await;
+    site.get("/tags/python/")
+        .await
+        .assert_not_contains("Legacy Post");
+    site.get("/tags/go/")
+        .await
+        .assert_contains("Unrelated Post");
+}
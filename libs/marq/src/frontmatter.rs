@@ -26,6 +26,58 @@ pub struct Frontmatter {
     #[facet(default)]
     pub template: Option<String>,
 
+    /// Publication date (`YYYY-MM-DD` or RFC 3339), kept as written
+    #[facet(default)]
+    pub date: Option<String>,
+
+    /// Last-updated date, same formats as `date`
+    #[facet(default)]
+    pub updated: Option<String>,
+
+    /// Drafts are previewed by dev servers but left out of production builds
+    #[facet(default)]
+    pub draft: bool,
+
+    /// Keeps the document unpublished until this moment, same formats as `date`
+    #[facet(default)]
+    pub publish_at: Option<String>,
+
+    /// Overrides the last path segment of the document's URL
+    #[facet(default)]
+    pub slug: Option<String>,
+
+    /// Former URLs of the document, redirected to its current one
+    #[facet(default)]
+    pub aliases: Vec<String>,
+
+    /// Split a section's page listing into pages of this many entries
+    #[facet(default)]
+    pub paginate_by: Option<u32>,
+
+    /// Feed formats a section publishes (`atom`, `rss`, `json`)
+    #[facet(default)]
+    pub feeds: Vec<String>,
+
+    /// Set to `false` to leave the document out of `sitemap.xml`
+    #[facet(default)]
+    pub sitemap: Option<bool>,
+
+    /// Sitemap `<priority>`, from 0.0 to 1.0
+    #[facet(default)]
+    pub sitemap_priority: Option<f64>,
+
+    /// Set to `false` to keep the document out of related-page suggestions
+    #[facet(default)]
+    pub related: Option<bool>,
+
+    /// Taxonomy terms keyed by taxonomy name (`[taxonomies] tags = [...]`)
+    #[facet(default)]
+    pub taxonomies: Value,
+
+    /// Series the document belongs to (`[series] name = "...", part = 2`)
+    #[facet(default)]
+    pub series: Value,
+
     /// Additional custom fields
     #[facet(default)]
     pub extra: Value,
@@ -224,6 +276,98 @@ mod tests {
         assert_eq!(body, "# Just Content");
     }
 
+    #[test]
+    fn test_parse_publishing_fields() {
+        let md = "+++\ntitle = \"Post\"\ndate = 2024-05-01\nupdated = \"2024-06-02T10:00:00Z\"\ndraft = true\nslug = \"hello\"\npublish_at = \"2024-06-01T09:00:00Z\"\n+++\n";
+        let (fm, _) = parse_frontmatter(md).unwrap();
+
+        assert_eq!(fm.date.as_deref(), Some("2024-05-01"));
+        assert_eq!(fm.updated.as_deref(), Some("2024-06-02T10:00:00Z"));
+        assert!(fm.draft);
+        assert_eq!(fm.slug.as_deref(), Some("hello"));
+        assert_eq!(fm.publish_at.as_deref(), Some("2024-06-01T09:00:00Z"));
+
+        let md = "---\ntitle: Post\ndate: 2024-05-01\ndraft: false\n---\n";
+        let (fm, _) = parse_frontmatter(md).unwrap();
+
+        assert_eq!(fm.date.as_deref(), Some("2024-05-01"));
+        assert!(!fm.draft);
+        assert_eq!(fm.slug, None);
+        assert_eq!(fm.publish_at, None);
+    }
+
+    #[test]
+    fn test_parse_paginate_by() {
+        let md = "+++\ntitle = \"Blog\"\npaginate_by = 5\n+++\n";
+        let (fm, _) = parse_frontmatter(md).unwrap();
+        assert_eq!(fm.paginate_by, Some(5));
+        assert!(fm.feeds.is_empty());
+
+        let (fm, _) = parse_frontmatter("+++\ntitle = \"Blog\"\n+++\n").unwrap();
+        assert_eq!(fm.paginate_by, None);
+    }
+
+    #[test]
+    fn test_parse_feeds() {
+        let md = "+++\ntitle = \"Blog\"\nfeeds = [\"atom\", \"rss\"]\n+++\n";
+        let (fm, _) = parse_frontmatter(md).unwrap();
+        assert_eq!(fm.feeds, ["atom", "rss"]);
+    }
+
+    #[test]
+    fn test_parse_aliases() {
+        let md = "+++\ntitle = \"Post\"\naliases = [\"/old/post/\"]\n+++\n";
+        let (fm, _) = parse_frontmatter(md).unwrap();
+        assert_eq!(fm.aliases, ["/old/post/"]);
+    }
+
+    #[test]
+    fn test_parse_sitemap_fields() {
+        let md = "+++\ntitle = \"Imprint\"\nsitemap = false\nsitemap_priority = 0.2\n+++\n";
+        let (fm, _) = parse_frontmatter(md).unwrap();
+        assert_eq!(fm.sitemap, Some(false));
+        assert_eq!(fm.sitemap_priority, Some(0.2));
+    }
+
+    #[test]
+    fn test_parse_related_opt_out() {
+        let md = "+++\ntitle = \"Imprint\"\nrelated = false\n+++\n";
+        let (fm, _) = parse_frontmatter(md).unwrap();
+        assert_eq!(fm.related, Some(false));
+    }
+
+    #[test]
+    fn test_parse_series_table() {
+        let md = "+++\ntitle = \"Part 2\"\n\n[series]\nname = \"Async Rust\"\npart = 2\n+++\n";
+        let (fm, _) = parse_frontmatter(md).unwrap();
+
+        use facet_value::DestructuredRef;
+        match fm.series.destructure_ref() {
+            DestructuredRef::Object(obj) => {
+                let name = obj.get("name").and_then(|v| v.as_string());
+                assert_eq!(name.map(|s| s.as_str()), Some("Async Rust"));
+                let part = obj.get("part").and_then(|v| v.as_number());
+                assert_eq!(part.and_then(|n| n.to_i64()), Some(2));
+            }
+            other => panic!("expected object, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_taxonomies_table() {
+        let md = "+++\ntitle = \"Post\"\n\n[taxonomies]\ntags = [\"rust\", \"async\"]\n+++\n";
+        let (fm, _) = parse_frontmatter(md).unwrap();
+
+        use facet_value::DestructuredRef;
+        match fm.taxonomies.destructure_ref() {
+            DestructuredRef::Object(obj) => {
+                let tags = obj.get("tags").expect("tags should exist");
+                assert_eq!(tags.as_array().map(|a| a.len()), Some(2));
+            }
+            other => panic!("expected object, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_frontmatter_with_extra_fields() {
         let md = "+++\ntitle = \"Test\"\n\n[extra]\ncustom_field = \"value\"\n+++\n# Content";